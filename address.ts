@@ -0,0 +1,46 @@
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
+import { PublicKey } from "@solana/web3.js";
+
+// CCTP carries every address as 32 bytes, EVM addresses are left-padded with 12 zero bytes
+const EVM_ADDRESS_LENGTH = 20;
+const EVM_ADDRESS_PADDING = 12;
+
+/**
+ * Converts a 20-byte hex EVM address (with or without 0x) into the 32-byte
+ * PublicKey expected by mint_recipient and destination_caller.
+ */
+export function evmAddressToPubkey(address: string): PublicKey {
+  const hex = address.startsWith("0x") ? address.slice(2) : address;
+  if (!/^[0-9a-fA-F]{40}$/.test(hex)) {
+    throw new Error(`Invalid EVM address: ${address}`);
+  }
+
+  const padded = Buffer.alloc(32);
+  Buffer.from(hex, "hex").copy(padded, EVM_ADDRESS_PADDING);
+  return new PublicKey(padded);
+}
+
+/**
+ * Converts a left-padded 32-byte PublicKey back into a 0x-prefixed EVM address.
+ * Throws if the first 12 bytes are not zero, as the wrapper would reject it for EVM domains.
+ */
+export function pubkeyToEvmAddress(pubkey: PublicKey): string {
+  const bytes = pubkey.toBuffer();
+  if (!bytes.subarray(0, EVM_ADDRESS_PADDING).every((byte) => byte === 0)) {
+    throw new Error(`${pubkey.toBase58()} is not a left-padded EVM address`);
+  }
+
+  return "0x" + bytes.subarray(32 - EVM_ADDRESS_LENGTH).toString("hex");
+}
+
+/**
+ * Returns the mint_recipient of a transfer to a Solana domain: CCTP mints into a
+ * token account, so a recipient wallet is replaced by its associated token account.
+ * The program cannot tell wallets from keypair token accounts, pass those directly.
+ */
+export function solanaMintRecipient(
+  wallet: PublicKey,
+  mint: PublicKey
+): PublicKey {
+  return getAssociatedTokenAddressSync(mint, wallet, true);
+}
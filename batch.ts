@@ -0,0 +1,45 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+// Must match BatchDepositForBurnParams::MAX_ENTRIES of the program
+export const MAX_BATCH_ENTRIES = 6;
+
+export interface BatchDepositEntry {
+  amount: BN;
+  destinationDomain: number;
+  mintRecipient: PublicKey;
+  // null uses the destination domain default
+  maxFee: BN | null;
+}
+
+export interface BatchChunk {
+  entries: BatchDepositEntry[];
+  totalAmount: BN;
+}
+
+/**
+ * Splits a payout list into chunks that each fit one batch_deposit_for_burn,
+ * with the total_amount the program checks for each chunk. Every chunk is sent
+ * in its own transaction, so a failed chunk does not revert the others.
+ */
+export function chunkBatchEntries(
+  entries: BatchDepositEntry[],
+  maxEntries: number = MAX_BATCH_ENTRIES
+): BatchChunk[] {
+  if (maxEntries < 1 || maxEntries > MAX_BATCH_ENTRIES) {
+    throw new Error(`maxEntries must be between 1 and ${MAX_BATCH_ENTRIES}`);
+  }
+
+  const chunks: BatchChunk[] = [];
+  for (let start = 0; start < entries.length; start += maxEntries) {
+    const chunk = entries.slice(start, start + maxEntries);
+    chunks.push({
+      entries: chunk,
+      totalAmount: chunk.reduce(
+        (total, entry) => total.add(entry.amount),
+        new BN(0)
+      ),
+    });
+  }
+  return chunks;
+}
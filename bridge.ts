@@ -76,14 +76,26 @@ describe("cctpintegration", () => {
     try {
       const messageTransmitter = await getCctpMessageTransmitter();
 
+      // The message is minted into the integration's inbound account and forwarded to the
+      // recipient named by the hook payload, so only the recipient's token account is needed
+      const recipientUsdc = await getAssociatedTokenAddress(
+        new PublicKey("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"), // USDC mint
+        recipient.publicKey
+      );
+
       await program.methods
-        .receiveCctpTransfer(message, attestation)
+        .receiveAndForward({
+          message: Buffer.from(message),
+          attestation: Buffer.from(attestation),
+        })
         .accounts({
-          recipient: recipient.publicKey,
-          messageTransmitter: messageTransmitter,
-          messageTransmitterProgram: MESSAGE_TRANSMITTER,
+          payer: provider.wallet.publicKey,
+          recipientTokenAccount: recipientUsdc,
+          cctp: {
+            messageTransmitter: messageTransmitter,
+            messageTransmitterProgram: MESSAGE_TRANSMITTER,
+          },
         })
-        .signers([recipient])
         .rpc();
 
       console.log("CCTP transfer received successfully");
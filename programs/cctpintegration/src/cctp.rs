@@ -0,0 +1,47 @@
+//! Addresses of the CCTP program accounts passed through by the wrapper
+
+use {
+    crate::error::ErrorCode, anchor_lang::prelude::*,
+    token_messenger_minter_v2::ID as TOKEN_MESSENGER_MINTER_ID,
+};
+
+/// Returns TokenMessengerMinter's sender_authority PDA for the bump stored in TokenMessenger
+pub fn sender_authority_address(authority_bump: u8) -> Result<Pubkey> {
+    Pubkey::create_program_address(
+        &[b"sender_authority", &[authority_bump]],
+        &TOKEN_MESSENGER_MINTER_ID,
+    )
+    .map_err(|_| error!(ErrorCode::InvalidSenderAuthority))
+}
+
+/// Returns the LocalToken PDA for the given mint and the bump stored in LocalToken
+pub fn local_token_address(mint: &Pubkey, bump: u8) -> Result<Pubkey> {
+    Pubkey::create_program_address(
+        &[b"local_token", mint.as_ref(), &[bump]],
+        &TOKEN_MESSENGER_MINTER_ID,
+    )
+    .map_err(|_| error!(ErrorCode::InvalidLocalToken))
+}
+
+/// Returns the RemoteTokenMessenger PDA of a destination domain
+pub fn remote_token_messenger_address(domain: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"remote_token_messenger", domain.to_string().as_bytes()],
+        &TOKEN_MESSENGER_MINTER_ID,
+    )
+    .0
+}
+
+/// Returns the denylist PDA TokenMessengerMinter checks for the burn token owner
+pub fn denylist_account_address(owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"denylist_account", owner.as_ref()],
+        &TOKEN_MESSENGER_MINTER_ID,
+    )
+    .0
+}
+
+/// Returns TokenMessengerMinter's event authority used by emit_cpi!
+pub fn event_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"__event_authority"], &TOKEN_MESSENGER_MINTER_ID).0
+}
@@ -0,0 +1,112 @@
+//! Accounts shared by the instructions that receive CCTP messages into the inbound token account
+
+use {
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Token, TokenAccount},
+    message_transmitter_v2::{
+        cpi::{accounts::ReceiveMessageContext, receive_message},
+        instructions::ReceiveMessageParams,
+        program::MessageTransmitterV2,
+        state::MessageTransmitter,
+    },
+    token_messenger_minter_v2::{
+        program::TokenMessengerMinterV2,
+        token_messenger_v2::state::{RemoteTokenMessenger, TokenMessenger},
+        token_minter_v2::state::{LocalToken, TokenMinter, TokenPair},
+    },
+};
+
+#[derive(Accounts)]
+pub struct CctpReceive<'info> {
+    // MessageTransmitter accounts
+    /// CHECK: MessageTransmitter's authority PDA for TokenMessengerMinter, checked by MessageTransmitter
+    pub message_transmitter_authority: UncheckedAccount<'info>,
+
+    #[account()]
+    pub message_transmitter: Box<Account<'info, MessageTransmitter>>,
+
+    /// CHECK: used nonce PDA, initialized by MessageTransmitter
+    #[account(mut)]
+    pub used_nonce: UncheckedAccount<'info>,
+
+    /// CHECK: MessageTransmitter event authority, checked by MessageTransmitter
+    pub message_transmitter_event_authority: UncheckedAccount<'info>,
+
+    pub message_transmitter_program: Program<'info, MessageTransmitterV2>,
+
+    // TokenMessengerMinter accounts passed through to handle_receive_finalized_message
+    #[account()]
+    pub token_messenger: Box<Account<'info, TokenMessenger>>,
+
+    #[account()]
+    pub remote_token_messenger: Box<Account<'info, RemoteTokenMessenger>>,
+
+    #[account()]
+    pub token_minter: Box<Account<'info, TokenMinter>>,
+
+    #[account(mut)]
+    pub local_token: Box<Account<'info, LocalToken>>,
+
+    #[account()]
+    pub token_pair: Box<Account<'info, TokenPair>>,
+
+    #[account(mut)]
+    pub fee_recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub custody_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: TokenMessengerMinter event authority, checked by TokenMessengerMinter
+    pub token_messenger_event_authority: UncheckedAccount<'info>,
+
+    pub token_messenger_minter_program: Program<'info, TokenMessengerMinterV2>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> CctpReceive<'info> {
+    /// CPIs MessageTransmitter::receive_message with `caller` signing through `caller_seeds`,
+    /// which calls back into TokenMessengerMinter to mint into `recipient_token_account`
+    pub fn receive_message(
+        &self,
+        payer: AccountInfo<'info>,
+        caller: AccountInfo<'info>,
+        recipient_token_account: AccountInfo<'info>,
+        system_program: AccountInfo<'info>,
+        caller_seeds: &[&[&[u8]]],
+        params: &ReceiveMessageParams,
+    ) -> Result<()> {
+        let cpi_accounts = ReceiveMessageContext {
+            payer,
+            caller,
+            authority_pda: self.message_transmitter_authority.to_account_info(),
+            message_transmitter: self.message_transmitter.to_account_info(),
+            used_nonce: self.used_nonce.to_account_info(),
+            receiver: self.token_messenger_minter_program.to_account_info(),
+            system_program,
+            event_authority: self.message_transmitter_event_authority.to_account_info(),
+            program: self.message_transmitter_program.to_account_info(),
+        };
+
+        // Remaining accounts in the order expected by handle_receive_finalized_message
+        let remaining_accounts = vec![
+            self.token_messenger.to_account_info(),
+            self.remote_token_messenger.to_account_info(),
+            self.token_minter.to_account_info(),
+            self.local_token.to_account_info(),
+            self.token_pair.to_account_info(),
+            self.fee_recipient_token_account.to_account_info(),
+            recipient_token_account,
+            self.custody_token_account.to_account_info(),
+            self.token_program.to_account_info(),
+            self.token_messenger_event_authority.to_account_info(),
+            self.token_messenger_minter_program.to_account_info(),
+        ];
+
+        let cpi_program = self.message_transmitter_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, caller_seeds)
+            .with_remaining_accounts(remaining_accounts);
+
+        receive_message(cpi_ctx, params.clone())
+    }
+}
@@ -0,0 +1,109 @@
+//! Error types
+
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fee must be less than total amount")]
+    InvalidFee,
+    #[msg("Invalid destination domain")]
+    InvalidDestinationDomain,
+    #[msg("Invalid recipient address")]
+    InvalidRecipient,
+    #[msg("CCTP transfer failed")]
+    CctpTransferFailed,
+    #[msg("Invalid authority")]
+    InvalidAuthority,
+    #[msg("Invalid bridge config state")]
+    InvalidBridgeConfigState,
+    #[msg("Invalid token mint")]
+    InvalidMint,
+    #[msg("Invalid vault token account")]
+    InvalidVaultAccount,
+    #[msg("Invalid fee token account")]
+    InvalidFeeAccount,
+    #[msg("Invalid sender authority")]
+    InvalidSenderAuthority,
+    #[msg("Invalid local token")]
+    InvalidLocalToken,
+    #[msg("Invalid denylist account")]
+    InvalidDenylistAccount,
+    #[msg("Invalid event authority")]
+    InvalidEventAuthority,
+    #[msg("Invalid hook payload")]
+    InvalidHookPayload,
+    #[msg("Unsupported hook payload version")]
+    UnsupportedHookPayloadVersion,
+    #[msg("Invalid hook program")]
+    InvalidHookProgram,
+    #[msg("Invalid hook accounts")]
+    InvalidHookAccounts,
+    #[msg("Invalid transfer status")]
+    InvalidTransferStatus,
+    #[msg("Invalid domain config")]
+    InvalidDomainConfig,
+    #[msg("Amount is outside of the destination domain bounds")]
+    InvalidAmount,
+    #[msg("Invalid destination caller")]
+    InvalidDestinationCaller,
+    #[msg("Invalid batch")]
+    InvalidBatch,
+    #[msg("Event account window has not expired")]
+    EventAccountWindowNotExpired,
+    #[msg("Invalid sponsor config")]
+    InvalidSponsorConfig,
+    #[msg("Sponsored deposits are disabled or fee payer is not the configured sponsor")]
+    InvalidSponsor,
+    #[msg("Sponsor surcharge exceeds the user limit")]
+    SurchargeExceeded,
+    #[msg("Invalid owner")]
+    InvalidOwner,
+    #[msg("Invalid fee manager")]
+    InvalidFeeManager,
+    #[msg("Invalid pauser")]
+    InvalidPauser,
+    #[msg("Invalid domain manager")]
+    InvalidDomainManager,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Invalid fee ledger")]
+    InvalidFeeLedger,
+    #[msg("Amount exceeds the fast transfer cap of the destination domain")]
+    FastTransferCapExceeded,
+    #[msg("Fee quote does not match the request")]
+    InvalidQuote,
+    #[msg("Fee quote expired")]
+    QuoteExpired,
+    #[msg("Fee quote is not signed by the quote signer")]
+    InvalidQuoteSignature,
+    #[msg("Invalid compliance")]
+    InvalidCompliance,
+    #[msg("Address is blocked")]
+    AddressBlocked,
+    #[msg("Account is denylisted by TokenMessengerMinter")]
+    DenylistedAccount,
+    #[msg("Invalid blocked address account")]
+    InvalidBlockedAddress,
+    #[msg("Invalid swap program")]
+    InvalidSwapProgram,
+    #[msg("Swap output below the minimum USDC amount")]
+    SwapOutputTooLow,
+    #[msg("Invalid partner config")]
+    InvalidPartnerConfig,
+    #[msg("Invalid partner token account")]
+    InvalidPartnerAccount,
+    #[msg("Amount exceeds the recoverable inbound balance")]
+    InboundAmountReserved,
+    #[msg("Burn token mint must be an SPL Token mint")]
+    UnsupportedBurnMint,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+    #[msg("Transfer record does not match the transfer")]
+    InvalidTransferRecord,
+    #[msg("Token account received less than the expected amount")]
+    InsufficientReceived,
+    #[msg("Hook is still registered")]
+    HookStillRegistered,
+}
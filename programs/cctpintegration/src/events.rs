@@ -0,0 +1,286 @@
+//! Events
+
+use {
+    crate::state::{DomainType, FeeTier, PartnerShare},
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct Initialized {
+    pub owner: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub vault_usdc: Pubkey,
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+}
+
+#[event]
+pub struct FeeVaultInitialized {
+    pub mint: Pubkey,
+    pub fee_vault: Pubkey,
+}
+
+#[event]
+pub struct InboundInitialized {
+    pub mint: Pubkey,
+    pub inbound_usdc: Pubkey,
+}
+
+#[event]
+pub struct OwnershipTransferStarted {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct FeeManagerUpdated {
+    pub previous_fee_manager: Pubkey,
+    pub new_fee_manager: Pubkey,
+}
+
+#[event]
+pub struct PauserChanged {
+    pub new_address: Pubkey,
+}
+
+#[event]
+pub struct Pause {}
+
+#[event]
+pub struct Unpause {}
+
+#[event]
+pub struct DomainManagerUpdated {
+    pub previous_domain_manager: Pubkey,
+    pub new_domain_manager: Pubkey,
+}
+
+#[event]
+pub struct BatchDepositForBurn {
+    pub depositor: Pubkey,
+    pub burn_token: Pubkey,
+    pub total_amount: u64,
+    pub entries: u16,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct InboundRecovered {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FeeUpdated {
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+}
+
+#[event]
+pub struct VaultUpdated {
+    pub previous_vault: Pubkey,
+    pub new_vault: Pubkey,
+}
+
+#[event]
+pub struct DomainConfigUpdated {
+    pub domain: u32,
+    pub domain_type: DomainType,
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub default_min_finality_threshold: u32,
+    pub default_max_fee: u64,
+    pub max_fee_buffer: u64,
+    pub max_fast_amount: u64,
+    pub inbound_usdc: Pubkey,
+}
+
+#[event]
+pub struct SponsorConfigUpdated {
+    pub enabled: bool,
+    pub fee_payer: Pubkey,
+    pub usdc_per_sol: u64,
+    pub tx_fee_lamports: u64,
+}
+
+#[event]
+pub struct HookRegistered {
+    pub hook_id: u8,
+    pub target_program: Pubkey,
+    pub token_account: Pubkey,
+}
+
+#[event]
+pub struct HookUnregistered {
+    pub hook_id: u8,
+}
+
+#[event]
+pub struct HookReceived {
+    pub hook_id: u8,
+    pub source_domain: u32,
+    pub nonce: [u8; 32],
+    pub amount: u64,
+}
+
+#[event]
+pub struct PendingHookCancelled {
+    pub hook_id: u8,
+    pub source_domain: u32,
+    pub nonce: [u8; 32],
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RelayerAdded {
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct RelayerRemoved {
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct BridgeFeeCollected {
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    // Credited to the fee vault, partner shares are reported by PartnerFeePaid
+    pub fee: u64,
+    // Volume fee tier of the fee, None for quoted fees
+    pub fee_tier: Option<u8>,
+    pub destination_domain: Option<u32>,
+}
+
+#[event]
+pub struct CctpDepositInitiated {
+    pub depositor: Pubkey,
+    pub burn_token: Pubkey,
+    pub amount: u64,
+    pub integration_fee: u64,
+    // Volume fee tier of the integration fee, None when the fee is not priced by fee_bps
+    pub fee_tier: Option<u8>,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    pub destination_caller: Pubkey,
+    pub max_fee: u64,
+    pub min_finality_threshold: u32,
+    pub message_sent_event_data: Pubkey,
+}
+
+#[event]
+pub struct EventRentPoolFunded {
+    pub funder: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct EventRentPoolWithdrawn {
+    pub recipient: Pubkey,
+    pub lamports: u64,
+}
+
+#[event]
+pub struct EventAccountReclaimed {
+    pub rent_payer: Pubkey,
+    pub message_sent_event_data: Pubkey,
+    pub user: Pubkey,
+    pub index: u64,
+}
+
+#[event]
+pub struct TransferAttested {
+    pub user: Pubkey,
+    pub index: u64,
+    pub message_sent_event_data: Pubkey,
+}
+
+#[event]
+pub struct CctpTransferCompleted {
+    pub source_domain: u32,
+    pub nonce: [u8; 32],
+    pub mint_token: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+    pub relayer_fee: u64,
+}
+
+#[event]
+pub struct QuoteSignerUpdated {
+    pub previous_quote_signer: Pubkey,
+    pub new_quote_signer: Pubkey,
+}
+
+#[event]
+pub struct ComplianceUpdated {
+    pub previous_compliance: Pubkey,
+    pub new_compliance: Pubkey,
+}
+
+#[event]
+pub struct AddressBlocked {
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct AddressUnblocked {
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct SwapConfigUpdated {
+    pub enabled: bool,
+    pub swap_program: Pubkey,
+}
+
+#[event]
+pub struct SwapExecuted {
+    pub user: Pubkey,
+    pub swap_program: Pubkey,
+    pub input_mint: Pubkey,
+    pub amount_in: u64,
+    pub usdc_out: u64,
+}
+
+#[event]
+pub struct PartnerConfigUpdated {
+    pub partner_id: u16,
+    pub enabled: bool,
+    pub shares: Vec<PartnerShare>,
+}
+
+#[event]
+pub struct PartnerFeePaid {
+    pub partner_id: u16,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub share_bps: u16,
+    // Amount credited to token_account
+    pub amount: u64,
+    pub destination_domain: Option<u32>,
+}
+
+#[event]
+pub struct FeeTiersUpdated {
+    pub fee_tiers: Vec<FeeTier>,
+}
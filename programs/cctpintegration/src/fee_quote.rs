@@ -0,0 +1,357 @@
+//! FeeQuote
+
+use {
+    crate::{
+        error::ErrorCode,
+        state::{BridgeConfig, UsedQuote},
+    },
+    anchor_lang::prelude::*,
+    anchor_lang::solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+
+/// Integration fee priced by the backend and signed by the configured quote signer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeQuote {
+    pub fee: u64,
+    // Total taken from the user, fee included: the bridge amount, or the burned
+    // amount plus the fee of a quoted deposit_for_burn_cctp
+    pub amount: u64,
+    // Destination domain of a quoted deposit_for_burn_cctp, None for a quoted bridge
+    pub destination_domain: Option<u32>,
+    pub user: Pubkey,
+    // Unix timestamp after which the quote is refused
+    pub expiry: i64,
+    // Unique per quote, a UsedQuote PDA is created from it on first use
+    pub quote_id: [u8; 32],
+}
+
+impl FeeQuote {
+    // Ed25519 program instruction layout, see solana_sdk::ed25519_instruction
+    const SIGNATURE_OFFSETS_START: usize = 2;
+    const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+    const PUBKEY_SERIALIZED_SIZE: usize = 32;
+    // Instruction index referring to the Ed25519 instruction itself
+    const CURRENT_INSTRUCTION: u16 = u16::MAX;
+    // Prefix of the signed message, keeps quote signatures from being valid for other messages
+    pub const MESSAGE_TAG: &'static [u8] = b"cctpintegration:fee_quote:v1";
+
+    /// Returns the message the quote signer signs: MESSAGE_TAG, the program id and the
+    /// bridge_config address followed by the Borsh serialized quote
+    pub fn message(&self, bridge_config: &Pubkey) -> Result<Vec<u8>> {
+        let mut message = Self::MESSAGE_TAG.to_vec();
+        message.extend_from_slice(crate::ID.as_ref());
+        message.extend_from_slice(bridge_config.as_ref());
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+
+    /// Checks the quote against the user, the amount and destination domain of the instruction
+    /// and the clock
+    pub fn validate(
+        &self,
+        user: &Pubkey,
+        amount: u64,
+        destination_domain: Option<u32>,
+    ) -> Result<()> {
+        require_keys_eq!(self.user, *user, ErrorCode::InvalidQuote);
+        require_eq!(self.amount, amount, ErrorCode::InvalidQuote);
+        require!(
+            self.destination_domain == destination_domain,
+            ErrorCode::InvalidQuote
+        );
+        require_gt!(amount, self.fee, ErrorCode::InvalidFee);
+        require_gte!(
+            self.expiry,
+            Clock::get()?.unix_timestamp,
+            ErrorCode::QuoteExpired
+        );
+
+        Ok(())
+    }
+
+    /// Verifies the signature of the quote and marks it used, `used_quote` is created from
+    /// the quote id by the instruction so a second use fails
+    pub fn redeem(
+        &self,
+        used_quote: Option<&mut Account<UsedQuote>>,
+        used_quote_bump: Option<u8>,
+        instructions_sysvar: Option<&AccountInfo>,
+        bridge_config: &Account<BridgeConfig>,
+    ) -> Result<()> {
+        let (Some(used_quote), Some(bump), Some(instructions_sysvar)) =
+            (used_quote, used_quote_bump, instructions_sysvar)
+        else {
+            return err!(ErrorCode::InvalidQuote);
+        };
+        self.verify_signature(
+            instructions_sysvar,
+            &bridge_config.quote_signer,
+            &bridge_config.key(),
+        )?;
+
+        used_quote.set_inner(UsedQuote {
+            quote_id: self.quote_id,
+            user: self.user,
+            used_at: Clock::get()?.unix_timestamp,
+            bump,
+        });
+
+        Ok(())
+    }
+
+    /// Checks that the instruction right before the current one is an Ed25519 program
+    /// instruction verifying a single signature of `signer` over the message of this quote
+    pub fn verify_signature(
+        &self,
+        instructions_sysvar: &AccountInfo,
+        signer: &Pubkey,
+        bridge_config: &Pubkey,
+    ) -> Result<()> {
+        require_keys_neq!(*signer, Pubkey::default(), ErrorCode::InvalidQuoteSignature);
+
+        let current_index = load_current_index_checked(instructions_sysvar)?;
+        require_gt!(current_index, 0, ErrorCode::InvalidQuoteSignature);
+        let ed25519_ix =
+            load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+        require_keys_eq!(
+            ed25519_ix.program_id,
+            ed25519_program::ID,
+            ErrorCode::InvalidQuoteSignature
+        );
+
+        let data = &ed25519_ix.data;
+        require!(
+            data.len() >= Self::SIGNATURE_OFFSETS_START + Self::SIGNATURE_OFFSETS_SERIALIZED_SIZE
+                && data[0] == 1,
+            ErrorCode::InvalidQuoteSignature
+        );
+
+        let read_u16 = |index: usize| {
+            let start = Self::SIGNATURE_OFFSETS_START + index * 2;
+            u16::from_le_bytes([data[start], data[start + 1]])
+        };
+        let signature_instruction_index = read_u16(1);
+        let public_key_offset = read_u16(2) as usize;
+        let public_key_instruction_index = read_u16(3);
+        let message_data_offset = read_u16(4) as usize;
+        let message_data_size = read_u16(5) as usize;
+        let message_instruction_index = read_u16(6);
+
+        // Signature, key and message must all be read from the Ed25519 instruction data
+        require!(
+            signature_instruction_index == Self::CURRENT_INSTRUCTION
+                && public_key_instruction_index == Self::CURRENT_INSTRUCTION
+                && message_instruction_index == Self::CURRENT_INSTRUCTION,
+            ErrorCode::InvalidQuoteSignature
+        );
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + Self::PUBKEY_SERIALIZED_SIZE)
+            .ok_or_else(|| error!(ErrorCode::InvalidQuoteSignature))?;
+        require!(
+            public_key == signer.as_ref(),
+            ErrorCode::InvalidQuoteSignature
+        );
+
+        let message = data
+            .get(message_data_offset..message_data_offset + message_data_size)
+            .ok_or_else(|| error!(ErrorCode::InvalidQuoteSignature))?;
+        require!(
+            message == self.message(bridge_config)?.as_slice(),
+            ErrorCode::InvalidQuoteSignature
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        anchor_lang::solana_program::sysvar::{
+            self,
+            instructions::{construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction},
+        },
+    };
+
+    // Ed25519 program instruction data offsets of a single signature
+    const PUBKEY_OFFSET: u16 = 16;
+    const SIGNATURE_OFFSET: u16 = 48;
+    const MESSAGE_OFFSET: u16 = 112;
+
+    fn bridge_config() -> Pubkey {
+        Pubkey::find_program_address(&[b"bridge_config"], &crate::ID).0
+    }
+
+    fn quote() -> FeeQuote {
+        FeeQuote {
+            fee: 100,
+            amount: 1_000_000,
+            destination_domain: Some(0),
+            user: Pubkey::new_unique(),
+            expiry: 0,
+            quote_id: [3; 32],
+        }
+    }
+
+    /// Ed25519 program instruction data with every offset pointing into the instruction itself
+    fn ed25519_data(signer: &Pubkey, message: &[u8]) -> Vec<u8> {
+        let mut data = vec![1, 0];
+        for value in [
+            SIGNATURE_OFFSET,
+            u16::MAX,
+            PUBKEY_OFFSET,
+            u16::MAX,
+            MESSAGE_OFFSET,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    /// Instructions sysvar data of a transaction with `previous` run right before the current instruction
+    fn instructions_sysvar_data(previous_program: &Pubkey, previous_data: &[u8]) -> Vec<u8> {
+        let current_program = crate::ID;
+        let payer = Pubkey::new_unique();
+        let instructions = [
+            BorrowedInstruction {
+                program_id: previous_program,
+                accounts: Vec::new(),
+                data: previous_data,
+            },
+            BorrowedInstruction {
+                program_id: &current_program,
+                accounts: vec![BorrowedAccountMeta {
+                    pubkey: &payer,
+                    is_signer: true,
+                    is_writable: true,
+                }],
+                data: &[],
+            },
+        ];
+        // The index of the current instruction is stored in the last two bytes
+        let mut data = construct_instructions_data(&instructions);
+        let len = data.len();
+        data[len - 2..].copy_from_slice(&1u16.to_le_bytes());
+        data
+    }
+
+    fn verify(quote: &FeeQuote, sysvar_data: &mut [u8], signer: &Pubkey) -> Result<()> {
+        let key = sysvar::instructions::ID;
+        let owner = sysvar::ID;
+        let mut lamports = 0;
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            sysvar_data,
+            &owner,
+            false,
+            0,
+        );
+        quote.verify_signature(&account, signer, &bridge_config())
+    }
+
+    #[test]
+    fn signed_quote_is_accepted() {
+        let quote = quote();
+        let signer = Pubkey::new_unique();
+        let mut sysvar_data = instructions_sysvar_data(
+            &ed25519_program::ID,
+            &ed25519_data(&signer, &quote.message(&bridge_config()).unwrap()),
+        );
+
+        assert!(verify(&quote, &mut sysvar_data, &signer).is_ok());
+    }
+
+    #[test]
+    fn quote_of_another_signer_is_rejected() {
+        let quote = quote();
+        let mut sysvar_data = instructions_sysvar_data(
+            &ed25519_program::ID,
+            &ed25519_data(
+                &Pubkey::new_unique(),
+                &quote.message(&bridge_config()).unwrap(),
+            ),
+        );
+
+        assert!(verify(&quote, &mut sysvar_data, &Pubkey::new_unique()).is_err());
+        assert!(verify(&quote, &mut sysvar_data, &Pubkey::default()).is_err());
+    }
+
+    #[test]
+    fn modified_quote_is_rejected() {
+        let quote = quote();
+        let signer = Pubkey::new_unique();
+        let mut sysvar_data = instructions_sysvar_data(
+            &ed25519_program::ID,
+            &ed25519_data(&signer, &quote.message(&bridge_config()).unwrap()),
+        );
+
+        let bridge_quote = FeeQuote {
+            destination_domain: None,
+            ..quote
+        };
+        assert!(verify(&bridge_quote, &mut sysvar_data, &signer).is_err());
+        let lower_fee = FeeQuote { fee: 1, ..quote };
+        assert!(verify(&lower_fee, &mut sysvar_data, &signer).is_err());
+    }
+
+    #[test]
+    fn quote_for_another_bridge_config_is_rejected() {
+        let quote = quote();
+        let signer = Pubkey::new_unique();
+        let mut sysvar_data = instructions_sysvar_data(
+            &ed25519_program::ID,
+            &ed25519_data(&signer, &quote.message(&Pubkey::new_unique()).unwrap()),
+        );
+        assert!(verify(&quote, &mut sysvar_data, &signer).is_err());
+
+        // The bare Borsh serialized quote is not a valid message either
+        let mut sysvar_data = instructions_sysvar_data(
+            &ed25519_program::ID,
+            &ed25519_data(&signer, &quote.try_to_vec().unwrap()),
+        );
+        assert!(verify(&quote, &mut sysvar_data, &signer).is_err());
+    }
+
+    #[test]
+    fn signature_must_come_from_the_ed25519_program() {
+        let quote = quote();
+        let signer = Pubkey::new_unique();
+        let mut sysvar_data = instructions_sysvar_data(
+            &Pubkey::new_unique(),
+            &ed25519_data(&signer, &quote.message(&bridge_config()).unwrap()),
+        );
+
+        assert!(verify(&quote, &mut sysvar_data, &signer).is_err());
+    }
+
+    #[test]
+    fn offsets_into_other_instructions_are_rejected() {
+        let quote = quote();
+        let signer = Pubkey::new_unique();
+        let mut data = ed25519_data(&signer, &quote.message(&bridge_config()).unwrap());
+        // Message instruction index read from another instruction of the transaction
+        data[14..16].copy_from_slice(&1u16.to_le_bytes());
+        let mut sysvar_data = instructions_sysvar_data(&ed25519_program::ID, &data);
+
+        assert!(verify(&quote, &mut sysvar_data, &signer).is_err());
+
+        let mut truncated = ed25519_data(&signer, &quote.message(&bridge_config()).unwrap());
+        truncated.truncate(MESSAGE_OFFSET as usize);
+        let mut sysvar_data = instructions_sysvar_data(&ed25519_program::ID, &truncated);
+
+        assert!(verify(&quote, &mut sysvar_data, &signer).is_err());
+    }
+}
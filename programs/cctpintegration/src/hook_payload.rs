@@ -0,0 +1,261 @@
+//! HookPayload
+
+use {crate::error::ErrorCode, anchor_lang::prelude::*};
+
+/// Hook kinds understood by cctpintegration decoders
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HookKind {
+    /// Forward the minted funds to `target`
+    Forward,
+    /// Call `target` with `calldata` after the mint
+    Call,
+    /// Hook type id resolved through the destination's hook registry
+    Custom(u8),
+}
+
+impl HookKind {
+    pub const FORWARD_ID: u8 = 0;
+    pub const CALL_ID: u8 = 1;
+
+    /// Returns the hook type id written into the payload
+    pub fn id(&self) -> u8 {
+        match self {
+            HookKind::Forward => Self::FORWARD_ID,
+            HookKind::Call => Self::CALL_ID,
+            HookKind::Custom(id) => *id,
+        }
+    }
+
+    /// Returns the kind for a hook type id read from the payload
+    pub fn from_id(id: u8) -> Self {
+        match id {
+            Self::FORWARD_ID => HookKind::Forward,
+            Self::CALL_ID => HookKind::Call,
+            id => HookKind::Custom(id),
+        }
+    }
+}
+
+/// Hook data emitted by cctpintegration into the CCTP burn message.
+///
+/// Layout (all fields are fixed size except calldata, which takes the rest of the payload):
+///
+/// | Field            | Bytes | Offset |
+/// |------------------|-------|--------|
+/// | magic            | 4     | 0      |
+/// | version          | 1     | 4      |
+/// | kind             | 1     | 5      |
+/// | target           | 32    | 6      |
+/// | client_reference | 32    | 38     |
+/// | relayer_fee      | 8     | 70     |
+/// | calldata         | *     | 78     |
+///
+/// Version 1 payloads have no relayer_fee field and calldata starts at offset 70.
+#[derive(Clone, Debug)]
+pub struct HookPayload<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> HookPayload<'a> {
+    pub const MAGIC: [u8; 4] = *b"CCTI";
+    pub const VERSION_1: u8 = 1;
+    pub const VERSION: u8 = 2;
+
+    // Indices of each field in the payload
+    const MAGIC_INDEX: usize = 0;
+    const VERSION_INDEX: usize = 4;
+    const KIND_INDEX: usize = 5;
+    const TARGET_INDEX: usize = 6;
+    const CLIENT_REFERENCE_INDEX: usize = 38;
+    const RELAYER_FEE_INDEX: usize = 70;
+    const CALLDATA_INDEX: usize = 78;
+    const CALLDATA_INDEX_V1: usize = 70;
+
+    /// Validates magic, version and size of the source bytes and returns a new payload
+    pub fn new(payload_bytes: &'a [u8]) -> Result<Self> {
+        require_gte!(
+            payload_bytes.len(),
+            Self::CALLDATA_INDEX_V1,
+            ErrorCode::InvalidHookPayload
+        );
+        let payload = Self {
+            data: payload_bytes,
+        };
+        require!(
+            payload.data[Self::MAGIC_INDEX..Self::VERSION_INDEX] == Self::MAGIC,
+            ErrorCode::InvalidHookPayload
+        );
+        require!(
+            payload.version() == Self::VERSION_1 || payload.version() == Self::VERSION,
+            ErrorCode::UnsupportedHookPayloadVersion
+        );
+        require_gte!(
+            payload.data.len(),
+            payload.calldata_index(),
+            ErrorCode::InvalidHookPayload
+        );
+        Ok(payload)
+    }
+
+    pub fn serialized_len(calldata_len: usize) -> Result<usize> {
+        Self::CALLDATA_INDEX
+            .checked_add(calldata_len)
+            .ok_or_else(|| error!(ErrorCode::InvalidHookPayload))
+    }
+
+    /// Serializes given fields into a hook payload
+    pub fn format_payload(
+        kind: HookKind,
+        target: &Pubkey,
+        client_reference: &[u8; 32],
+        relayer_fee: u64,
+        calldata: &[u8],
+    ) -> Result<Vec<u8>> {
+        // Custom ids must not alias the builtin kinds, otherwise decoders would misread them
+        require!(
+            HookKind::from_id(kind.id()) == kind,
+            ErrorCode::InvalidHookPayload
+        );
+
+        let mut output = vec![0; Self::serialized_len(calldata.len())?];
+
+        output[Self::MAGIC_INDEX..Self::VERSION_INDEX].copy_from_slice(&Self::MAGIC);
+        output[Self::VERSION_INDEX] = Self::VERSION;
+        output[Self::KIND_INDEX] = kind.id();
+        output[Self::TARGET_INDEX..Self::CLIENT_REFERENCE_INDEX].copy_from_slice(target.as_ref());
+        output[Self::CLIENT_REFERENCE_INDEX..Self::RELAYER_FEE_INDEX]
+            .copy_from_slice(client_reference);
+        output[Self::RELAYER_FEE_INDEX..Self::CALLDATA_INDEX]
+            .copy_from_slice(&relayer_fee.to_be_bytes());
+        output[Self::CALLDATA_INDEX..].copy_from_slice(calldata);
+
+        Ok(output)
+    }
+
+    /// Returns version field
+    pub fn version(&self) -> u8 {
+        self.data[Self::VERSION_INDEX]
+    }
+
+    /// Returns kind field
+    pub fn kind(&self) -> HookKind {
+        HookKind::from_id(self.data[Self::KIND_INDEX])
+    }
+
+    /// Returns target field
+    pub fn target(&self) -> Result<Pubkey> {
+        Pubkey::try_from(&self.data[Self::TARGET_INDEX..Self::CLIENT_REFERENCE_INDEX])
+            .map_err(|_| error!(ErrorCode::InvalidHookPayload))
+    }
+
+    /// Returns client_reference field
+    pub fn client_reference(&self) -> Result<[u8; 32]> {
+        self.data[Self::CLIENT_REFERENCE_INDEX..Self::RELAYER_FEE_INDEX]
+            .try_into()
+            .map_err(|_| error!(ErrorCode::InvalidHookPayload))
+    }
+
+    /// Returns relayer_fee field, version 1 payloads carry no relayer fee
+    pub fn relayer_fee(&self) -> Result<u64> {
+        if self.version() == Self::VERSION_1 {
+            return Ok(0);
+        }
+        Ok(u64::from_be_bytes(
+            self.data[Self::RELAYER_FEE_INDEX..Self::CALLDATA_INDEX]
+                .try_into()
+                .map_err(|_| error!(ErrorCode::InvalidHookPayload))?,
+        ))
+    }
+
+    /// Returns calldata field
+    pub fn calldata(&self) -> &[u8] {
+        &self.data[self.calldata_index()..]
+    }
+
+    fn calldata_index(&self) -> usize {
+        if self.version() == Self::VERSION_1 {
+            Self::CALLDATA_INDEX_V1
+        } else {
+            Self::CALLDATA_INDEX
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_round_trips() {
+        let target = Pubkey::new_unique();
+        let client_reference = [7u8; 32];
+        let bytes =
+            HookPayload::format_payload(HookKind::Call, &target, &client_reference, 250, b"data")
+                .unwrap();
+        assert_eq!(bytes.len(), HookPayload::serialized_len(4).unwrap());
+
+        let payload = HookPayload::new(&bytes).unwrap();
+        assert_eq!(payload.version(), HookPayload::VERSION);
+        assert_eq!(payload.kind(), HookKind::Call);
+        assert_eq!(payload.target().unwrap(), target);
+        assert_eq!(payload.client_reference().unwrap(), client_reference);
+        assert_eq!(payload.relayer_fee().unwrap(), 250);
+        assert_eq!(payload.calldata(), b"data");
+    }
+
+    #[test]
+    fn version_1_payload_has_no_relayer_fee() {
+        let target = Pubkey::new_unique();
+        let mut bytes = HookPayload::MAGIC.to_vec();
+        bytes.push(HookPayload::VERSION_1);
+        bytes.push(HookKind::FORWARD_ID);
+        bytes.extend_from_slice(target.as_ref());
+        bytes.extend_from_slice(&[1u8; 32]);
+        bytes.extend_from_slice(b"calldata");
+
+        let payload = HookPayload::new(&bytes).unwrap();
+        assert_eq!(payload.kind(), HookKind::Forward);
+        assert_eq!(payload.target().unwrap(), target);
+        assert_eq!(payload.relayer_fee().unwrap(), 0);
+        assert_eq!(payload.calldata(), b"calldata");
+    }
+
+    #[test]
+    fn custom_kinds_must_not_alias_builtin_kinds() {
+        let target = Pubkey::new_unique();
+
+        let bytes =
+            HookPayload::format_payload(HookKind::Custom(9), &target, &[0; 32], 0, &[]).unwrap();
+        assert_eq!(
+            HookPayload::new(&bytes).unwrap().kind(),
+            HookKind::Custom(9)
+        );
+
+        assert!(HookPayload::format_payload(
+            HookKind::Custom(HookKind::FORWARD_ID),
+            &target,
+            &[0; 32],
+            0,
+            &[]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn malformed_payloads_are_rejected() {
+        let bytes =
+            HookPayload::format_payload(HookKind::Forward, &Pubkey::new_unique(), &[0; 32], 0, &[])
+                .unwrap();
+
+        // Shorter than the fixed fields of the version
+        assert!(HookPayload::new(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] ^= 1;
+        assert!(HookPayload::new(&bad_magic).is_err());
+
+        let mut bad_version = bytes;
+        bad_version[4] = HookPayload::VERSION + 1;
+        assert!(HookPayload::new(&bad_version).is_err());
+    }
+}
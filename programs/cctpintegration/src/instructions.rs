@@ -0,0 +1,64 @@
+//! Instructions
+
+pub mod accept_ownership;
+pub mod add_relayer;
+pub mod batch_deposit_for_burn;
+pub mod block_address;
+pub mod bridge;
+pub mod bridge_and_deposit_for_burn;
+pub mod cancel_pending_hook;
+pub mod deposit_for_burn_cctp;
+pub mod deposit_for_burn_relayed_cctp;
+pub mod deposit_for_burn_sponsored_cctp;
+pub mod deposit_for_burn_with_hook_cctp;
+pub mod deposit_for_burn_with_speed_cctp;
+pub mod execute_hook;
+pub mod fund_event_rent_pool;
+pub mod initialize;
+pub mod initialize_fee_vault;
+pub mod initialize_inbound;
+pub mod mark_transfer_attested;
+pub mod pause;
+pub mod quote_transfer;
+pub mod receive_and_forward;
+pub mod receive_for_hook;
+pub mod reclaim_event_account;
+pub mod recover_inbound;
+pub mod register_hook;
+pub mod relay_and_forward;
+pub mod remove_relayer;
+pub mod set_domain_config;
+pub mod set_fee;
+pub mod set_fee_accounts;
+pub mod set_fee_tiers;
+pub mod set_partner_config;
+pub mod set_quote_signer;
+pub mod set_sponsor_config;
+pub mod set_swap_config;
+pub mod swap_and_deposit_for_burn_cctp;
+pub mod transfer_ownership;
+pub mod unblock_address;
+pub mod unpause;
+pub mod unregister_hook;
+pub mod update_compliance;
+pub mod update_domain_manager;
+pub mod update_fee_manager;
+pub mod update_pauser;
+pub mod withdraw_event_rent_pool;
+pub mod withdraw_fees;
+
+pub use {
+    accept_ownership::*, add_relayer::*, batch_deposit_for_burn::*, block_address::*, bridge::*,
+    bridge_and_deposit_for_burn::*, cancel_pending_hook::*, deposit_for_burn_cctp::*,
+    deposit_for_burn_relayed_cctp::*, deposit_for_burn_sponsored_cctp::*,
+    deposit_for_burn_with_hook_cctp::*, deposit_for_burn_with_speed_cctp::*, execute_hook::*,
+    fund_event_rent_pool::*, initialize::*, initialize_fee_vault::*, initialize_inbound::*,
+    mark_transfer_attested::*, pause::*, quote_transfer::*, receive_and_forward::*,
+    receive_for_hook::*, reclaim_event_account::*, recover_inbound::*, register_hook::*,
+    relay_and_forward::*, remove_relayer::*, set_domain_config::*, set_fee::*, set_fee_accounts::*,
+    set_fee_tiers::*, set_partner_config::*, set_quote_signer::*, set_sponsor_config::*,
+    set_swap_config::*, swap_and_deposit_for_burn_cctp::*, transfer_ownership::*,
+    unblock_address::*, unpause::*, unregister_hook::*, update_compliance::*,
+    update_domain_manager::*, update_fee_manager::*, update_pauser::*, withdraw_event_rent_pool::*,
+    withdraw_fees::*,
+};
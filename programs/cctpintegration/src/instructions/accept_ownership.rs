@@ -0,0 +1,40 @@
+//! AcceptOwnership instruction handler
+
+use {
+    crate::{error::ErrorCode, events::OwnershipTransferred, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptOwnershipContext<'info> {
+    #[account()]
+    pub pending_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = pending_owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction handler
+pub fn accept_ownership(ctx: Context<AcceptOwnershipContext>) -> Result<()> {
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+
+    let previous_owner = bridge_config.owner;
+
+    // update owners
+    bridge_config.owner = bridge_config.pending_owner;
+    bridge_config.pending_owner = Pubkey::default();
+
+    emit_cpi!(OwnershipTransferred {
+        previous_owner,
+        new_owner: bridge_config.owner
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! AddRelayer instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::RelayerAdded,
+        state::{BridgeConfig, Relayer},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: AddRelayerParams)]
+pub struct AddRelayerContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Relayer::DISCRIMINATOR.len() + Relayer::INIT_SPACE,
+        seeds = [b"relayer", params.relayer.as_ref()],
+        bump
+    )]
+    pub relayer: Box<Account<'info, Relayer>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct AddRelayerParams {
+    pub relayer: Pubkey,
+}
+
+// Instruction handler
+pub fn add_relayer(ctx: Context<AddRelayerContext>, params: &AddRelayerParams) -> Result<()> {
+    require_keys_neq!(
+        params.relayer,
+        Pubkey::default(),
+        ErrorCode::InvalidAuthority
+    );
+
+    let relayer = ctx.accounts.relayer.as_mut();
+    relayer.relayer = params.relayer;
+    relayer.bump = ctx.bumps.relayer;
+
+    emit_cpi!(RelayerAdded {
+        relayer: params.relayer,
+    });
+
+    Ok(())
+}
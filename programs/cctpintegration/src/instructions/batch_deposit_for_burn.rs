@@ -0,0 +1,271 @@
+//! BatchDepositForBurn instruction handler
+
+use {
+    crate::{
+        cctp,
+        error::ErrorCode,
+        events::BatchDepositForBurn,
+        instructions::deposit_for_burn_cctp::*,
+        state::{DomainConfig, TransferRecord, TransferSpeed},
+    },
+    anchor_lang::{
+        prelude::*,
+        system_program::{
+            allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+        },
+    },
+};
+
+// Instruction accounts
+// The first entry is burned with the per-entry accounts of cctp, cctp.payer pays the
+// MessageSent account and transfer record rent of every entry, batches charge no integration fee.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BatchDepositForBurnContext<'info> {
+    pub cctp: CctpTransfer<'info>,
+    // remaining accounts: for each entry after the first, in order
+    //   message_sent_event_data (signer, writable),
+    //   remote_token_messenger of the entry destination domain,
+    //   domain_config of the entry destination domain,
+    //   BlockedAddress PDA of the entry mint recipient (must not exist),
+    //   TransferRecord PDA of the entry (writable, created by the batch)
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchDepositEntry {
+    pub amount: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    // Unset uses the destination domain default, ignored with a batch speed
+    pub max_fee: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchDepositForBurnParams {
+    pub entries: Vec<BatchDepositEntry>,
+    // Sum of all entry amounts, guards against a truncated or modified entry list
+    pub total_amount: u64,
+    // Unset uses the destination domain default, ignored with a batch speed
+    pub min_finality_threshold: Option<u32>,
+    // Sets the finality threshold and max fee of every entry as in deposit_for_burn_with_speed_cctp
+    pub speed: Option<TransferSpeed>,
+}
+
+impl BatchDepositForBurnParams {
+    // Bound by the 1232-byte transaction size, not by compute: with the other accounts in a
+    // lookup table, every entry still adds about 160 bytes (MessageSent account signature and
+    // key, entry data, account indexes) to about 280 bytes of fixed overhead. Larger payouts
+    // are split over several transactions, see chunkBatchEntries in batch.ts
+    pub const MAX_ENTRIES: usize = 6;
+    pub const ACCOUNTS_PER_ENTRY: usize = 5;
+}
+
+// Instruction handler
+pub fn batch_deposit_for_burn<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, BatchDepositForBurnContext<'info>>,
+    params: &BatchDepositForBurnParams,
+) -> Result<()> {
+    require!(
+        !params.entries.is_empty()
+            && params.entries.len() <= BatchDepositForBurnParams::MAX_ENTRIES,
+        ErrorCode::InvalidBatch
+    );
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        (params.entries.len() - 1) * BatchDepositForBurnParams::ACCOUNTS_PER_ENTRY,
+        ErrorCode::InvalidBatch
+    );
+
+    let total_amount = params
+        .entries
+        .iter()
+        .try_fold(0u64, |total, entry| total.checked_add(entry.amount))
+        .ok_or_else(|| error!(ErrorCode::InvalidBatch))?;
+    require_eq!(total_amount, params.total_amount, ErrorCode::InvalidBatch);
+    require_gte!(
+        ctx.accounts.cctp.user_usdc.amount,
+        total_amount,
+        ErrorCode::InvalidBatch
+    );
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let mut entry_accounts =
+        remaining_accounts.chunks(BatchDepositForBurnParams::ACCOUNTS_PER_ENTRY);
+    for (index, entry) in params.entries.iter().enumerate() {
+        if index > 0 {
+            let accounts = entry_accounts
+                .next()
+                .ok_or_else(|| error!(ErrorCode::InvalidBatch))?;
+            load_entry(&mut ctx.accounts.cctp, &mut ctx.bumps.cctp, entry, accounts)?;
+        }
+
+        let (max_fee, min_finality_threshold) = match params.speed {
+            Some(speed) => {
+                let min_fee_amount = ctx
+                    .accounts
+                    .cctp
+                    .token_messenger
+                    .get_min_fee_amount(entry.amount)?;
+                (
+                    Some(
+                        ctx.accounts
+                            .cctp
+                            .domain_config
+                            .max_fee_with_buffer(min_fee_amount)?,
+                    ),
+                    Some(speed.min_finality_threshold()),
+                )
+            }
+            None => (entry.max_fee, params.min_finality_threshold),
+        };
+
+        let cpi_params = ctx.accounts.cctp.burn(
+            &ctx.bumps.cctp,
+            &DepositForBurnCctpParams {
+                amount: entry.amount,
+                destination_domain: entry.destination_domain,
+                mint_recipient: entry.mint_recipient,
+                destination_caller: Pubkey::default(),
+                max_fee,
+                min_finality_threshold,
+            },
+            EventRentPayer::Payer,
+        )?;
+
+        emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, 0));
+    }
+
+    emit_cpi!(BatchDepositForBurn {
+        depositor: ctx.accounts.cctp.user.key(),
+        burn_token: ctx.accounts.cctp.burn_token_mint.key(),
+        total_amount,
+        entries: params.entries.len() as u16,
+    });
+
+    Ok(())
+}
+
+/// Points the per-entry accounts of cctp at the accounts of the next entry and creates its
+/// transfer record, the burn then checks them as for a single deposit
+fn load_entry<'info>(
+    cctp: &mut CctpTransfer<'info>,
+    bumps: &mut CctpTransferBumps,
+    entry: &BatchDepositEntry,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let domain_config = Account::<DomainConfig>::try_from(&accounts[2])?;
+    require_keys_eq!(
+        domain_config.key(),
+        Pubkey::create_program_address(
+            &[
+                b"domain_config",
+                &entry.destination_domain.to_le_bytes(),
+                &[domain_config.bump]
+            ],
+            &crate::ID
+        )
+        .map_err(|_| error!(ErrorCode::InvalidDomainConfig))?,
+        ErrorCode::InvalidDomainConfig
+    );
+    require_keys_eq!(
+        accounts[1].key(),
+        cctp::remote_token_messenger_address(entry.destination_domain),
+        ErrorCode::InvalidDestinationDomain
+    );
+
+    // The record of the previous entry is only written back by exit, persist it before replacing it
+    cctp.transfer_record.exit(&crate::ID)?;
+
+    let user = cctp.user.key();
+    let index = cctp.user_transfer_counter.next_index.to_le_bytes();
+    let (transfer_record, transfer_record_bump) =
+        Pubkey::find_program_address(&[b"transfer_record", user.as_ref(), &index], &crate::ID);
+    require_keys_eq!(
+        accounts[4].key(),
+        transfer_record,
+        ErrorCode::InvalidTransferRecord
+    );
+    create_transfer_record(
+        cctp,
+        &accounts[4],
+        &[
+            b"transfer_record",
+            user.as_ref(),
+            &index,
+            &[transfer_record_bump],
+        ],
+    )?;
+
+    cctp.message_sent_event_data = Signer::try_from(&accounts[0])?;
+    *cctp.remote_token_messenger = Account::try_from(&accounts[1])?;
+    *cctp.domain_config = domain_config;
+    cctp.blocked_mint_recipient = UncheckedAccount::try_from(&accounts[3]);
+    *cctp.transfer_record = Account::try_from_unchecked(&accounts[4])?;
+    bumps.transfer_record = transfer_record_bump;
+
+    Ok(())
+}
+
+/// Creates the TransferRecord account paid by cctp.payer, as the init constraint does
+fn create_transfer_record<'info>(
+    cctp: &CctpTransfer<'info>,
+    transfer_record: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let space = TransferRecord::DISCRIMINATOR.len() + TransferRecord::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let system_program = cctp.system_program.to_account_info();
+    let signer_seeds = &[seeds];
+
+    // Lamports may have been sent to the address beforehand, top them up instead of failing
+    let lamports = transfer_record.lamports();
+    if lamports == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: cctp.payer.to_account_info(),
+                    to: transfer_record.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    if lamports < rent {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: cctp.payer.to_account_info(),
+                    to: transfer_record.clone(),
+                },
+            ),
+            rent - lamports,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: transfer_record.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: transfer_record.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
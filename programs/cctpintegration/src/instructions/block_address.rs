@@ -0,0 +1,64 @@
+//! BlockAddress instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::AddressBlocked,
+        state::{BlockedAddress, BridgeConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: BlockAddressParams)]
+pub struct BlockAddressContext<'info> {
+    #[account(mut)]
+    pub compliance: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = compliance @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init,
+        payer = compliance,
+        space = BlockedAddress::DISCRIMINATOR.len() + BlockedAddress::INIT_SPACE,
+        seeds = [b"blocked_address", params.address.as_ref()],
+        bump
+    )]
+    pub blocked_address: Box<Account<'info, BlockedAddress>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct BlockAddressParams {
+    pub address: Pubkey,
+}
+
+// Instruction handler
+pub fn block_address(ctx: Context<BlockAddressContext>, params: &BlockAddressParams) -> Result<()> {
+    require_keys_neq!(
+        params.address,
+        Pubkey::default(),
+        ErrorCode::InvalidRecipient
+    );
+
+    ctx.accounts.blocked_address.set_inner(BlockedAddress {
+        address: params.address,
+        blocked_at: Clock::get()?.unix_timestamp,
+        bump: ctx.bumps.blocked_address,
+    });
+
+    emit_cpi!(AddressBlocked {
+        address: params.address,
+    });
+
+    Ok(())
+}
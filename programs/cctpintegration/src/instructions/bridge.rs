@@ -0,0 +1,233 @@
+//! Bridge instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::BridgeFeeCollected,
+        fee_quote::FeeQuote,
+        partner_fee::{self, FeeTransfer, PartnerFeePayment},
+        state::{BridgeConfig, FeeLedger, PartnerConfig, UsedQuote, UserStats},
+        token_transfer,
+    },
+    anchor_lang::{prelude::*, solana_program::sysvar},
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+// Instruction accounts
+// With a fee quote, the transaction must carry an Ed25519 program instruction signing it
+// right before this instruction.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(amount: u64, partner_id: Option<u16>, quote: Option<FeeQuote>)]
+pub struct BridgeContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == bridge_config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub user_usdc: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        address = bridge_config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        address = bridge_config.vault_usdc @ ErrorCode::InvalidVaultAccount
+    )]
+    pub vault_usdc: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_ledger", bridge_config.usdc_mint.as_ref()],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Account<'info, FeeLedger>,
+
+    #[account(
+        mut,
+        address = fee_ledger.fee_vault @ ErrorCode::InvalidFeeAccount
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    // Partner selected by the partner id of the instruction, None without a partner
+    #[account(
+        seeds = [b"partner_config".as_ref(), &partner_config.partner_id.to_le_bytes()],
+        bump = partner_config.bump,
+        constraint = partner_config.enabled @ ErrorCode::InvalidPartnerConfig
+    )]
+    pub partner_config: Option<Account<'info, PartnerConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", user.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: BlockedAddress PDA of the user, must not exist
+    #[account(
+        seeds = [b"blocked_address", user.key().as_ref()],
+        bump,
+        constraint = blocked_user.data_is_empty() @ ErrorCode::AddressBlocked
+    )]
+    pub blocked_user: UncheckedAccount<'info>,
+
+    // Marks the fee quote used, None without a quote
+    #[account(
+        init,
+        payer = user,
+        space = UsedQuote::DISCRIMINATOR.len() + UsedQuote::INIT_SPACE,
+        seeds = [
+            b"used_quote".as_ref(),
+            quote.as_ref().map_or(&[0u8; 32][..], |quote| quote.quote_id.as_ref())
+        ],
+        bump
+    )]
+    pub used_quote: Option<Box<Account<'info, UsedQuote>>>,
+
+    /// CHECK: instructions sysvar, read to find the Ed25519 instruction. None without a quote
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+impl<'info> BridgeContext<'info> {
+    /// Adds `amount` to the rolling volume of the user, returns the fee tier of the volume before it
+    pub fn record_volume(&mut self, bump: u8, amount: u64) -> Result<u8> {
+        let volume =
+            self.user_stats
+                .record(self.user.key(), bump, Clock::get()?.unix_timestamp, amount);
+        Ok(self.bridge_config.get_fee_tier(volume))
+    }
+
+    /// Moves `fee` to the partners of `partner_id` and the fee vault and the rest of `amount`
+    /// to the vault, returns the fee credited to the fee vault and the partner payments
+    pub fn transfer(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        amount: u64,
+        fee: u64,
+        partner_id: Option<u16>,
+    ) -> Result<(u64, Vec<PartnerFeePayment>)> {
+        let (partner_accounts, transfer_hook_accounts) = partner_fee::split_remaining_accounts(
+            self.partner_config.as_deref(),
+            partner_id,
+            remaining_accounts,
+        )?;
+        let fee_transfer = FeeTransfer {
+            token_program: self.token_program.to_account_info(),
+            from: self.user_usdc.to_account_info(),
+            mint: self.usdc_mint.to_account_info(),
+            authority: self.user.to_account_info(),
+            decimals: self.usdc_mint.decimals,
+            transfer_hook_accounts,
+        };
+
+        // Pay the partner shares first, the fee vault gets the rest of the fee
+        let partner_payments = match &self.partner_config {
+            Some(partner_config) => partner_fee::pay_partner_shares(
+                partner_config,
+                &mut self.fee_ledger,
+                &fee_transfer,
+                partner_accounts,
+                fee,
+            )?,
+            None => Vec::new(),
+        };
+        let vault_fee = fee - partner_fee::total_sent(&partner_payments);
+
+        // Transfer fee to the fee vault, the ledger records what the vault was credited
+        let fee_received = token_transfer::transfer_checked_net(
+            fee_transfer.context(self.fee_vault.to_account_info()),
+            &mut self.fee_vault,
+            vault_fee,
+            fee_transfer.decimals,
+        )?;
+        self.fee_ledger.record_collected(None, fee_received)?;
+
+        // Transfer remaining amount to the vault
+        token_transfer::transfer_checked(
+            fee_transfer.context(self.vault_usdc.to_account_info()),
+            amount - fee,
+            fee_transfer.decimals,
+        )?;
+
+        Ok((fee_received, partner_payments))
+    }
+}
+
+// Instruction handler
+// Remaining accounts: with a partner, the token accounts of the partner shares in config order,
+// then the transfer hook accounts of the mint forwarded to transfer_checked.
+// The fee is computed on the amount left after the Token-2022 transfer fee of the mint,
+// or taken from the quote, whose amount is the gross amount taken from the user.
+pub fn bridge<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BridgeContext<'info>>,
+    amount: u64,
+    partner_id: Option<u16>,
+    quote: Option<FeeQuote>,
+) -> Result<()> {
+    let net_amount = token_transfer::net_amount(&ctx.accounts.usdc_mint.to_account_info(), amount)?;
+    let fee_tier = ctx.accounts.record_volume(ctx.bumps.user_stats, amount)?;
+    let (fee, fee_tier) = match &quote {
+        // Quoted fees count towards the volume but are never tiered nor split with partners
+        Some(quote) => {
+            require!(partner_id.is_none(), ErrorCode::InvalidQuote);
+            quote.validate(&ctx.accounts.user.key(), amount, None)?;
+            quote.redeem(
+                ctx.accounts.used_quote.as_deref_mut(),
+                ctx.bumps.used_quote,
+                ctx.accounts.instructions_sysvar.as_deref(),
+                &ctx.accounts.bridge_config,
+            )?;
+            (quote.fee, None)
+        }
+        None => {
+            require!(ctx.accounts.used_quote.is_none(), ErrorCode::InvalidQuote);
+            let fee = ctx
+                .accounts
+                .bridge_config
+                .get_tiered_fee_amount(net_amount, fee_tier)?;
+            (fee, Some(fee_tier))
+        }
+    };
+
+    let (fee_received, partner_payments) =
+        ctx.accounts
+            .transfer(ctx.remaining_accounts, amount, fee, partner_id)?;
+
+    let depositor = ctx.accounts.user.key();
+    let mint = ctx.accounts.usdc_mint.key();
+    if let Some(partner_id) = partner_id {
+        for payment in &partner_payments {
+            emit_cpi!(payment.event(partner_id, depositor, mint, None));
+        }
+    }
+
+    emit_cpi!(BridgeFeeCollected {
+        depositor,
+        mint,
+        amount,
+        fee: fee_received,
+        fee_tier,
+        destination_domain: None,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+//! BridgeAndDepositForBurn instruction handler
+
+use {
+    crate::{
+        cctp,
+        error::ErrorCode,
+        events::{BridgeFeeCollected, CctpDepositInitiated},
+        instructions::deposit_for_burn_cctp::*,
+        partner_fee::{self, FeeTransfer},
+        state::{FeeLedger, PartnerConfig},
+        token_transfer,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::TokenAccount,
+};
+
+// Instruction accounts
+// The integration fee is taken from cctp.user_usdc, the rest is moved to the staging account
+// and burned from there with integration_authority as the owner.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BridgeAndDepositForBurnContext<'info> {
+    pub cctp: CctpTransfer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_ledger", cctp.bridge_config.usdc_mint.as_ref()],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Box<Account<'info, FeeLedger>>,
+
+    #[account(
+        mut,
+        address = fee_ledger.fee_vault @ ErrorCode::InvalidFeeAccount
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Partner selected by params.partner_id, None without a partner
+    #[account(
+        seeds = [b"partner_config".as_ref(), &partner_config.partner_id.to_le_bytes()],
+        bump = partner_config.bump,
+        constraint = partner_config.enabled @ ErrorCode::InvalidPartnerConfig
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+
+    /// CHECK: empty PDA, signs the burn as owner of the staging token account
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = cctp.bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"staging",
+            cctp.bridge_config.usdc_mint.as_ref()
+        ],
+        bump = cctp.bridge_config.staging_bump,
+        constraint = staging_usdc.mint == cctp.burn_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub staging_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: denylist PDA of integration_authority, the burn token owner checked by
+    /// TokenMessengerMinter. The user is screened through cctp.denylist_account
+    #[account(
+        constraint = authority_denylist_account.key() == cctp::denylist_account_address(&integration_authority.key()) @ ErrorCode::InvalidDenylistAccount
+    )]
+    pub authority_denylist_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BridgeAndDepositForBurnParams {
+    pub amount: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    // For no destination caller, use Pubkey::default()
+    pub destination_caller: Pubkey,
+    // Unset max_fee and min_finality_threshold use the defaults of the destination domain
+    pub max_fee: Option<u64>,
+    pub min_finality_threshold: Option<u32>,
+    // Partner sharing the integration fee, must match partner_config
+    pub partner_id: Option<u16>,
+}
+
+// Instruction handler
+// params.amount is the gross amount taken from the user, the integration fee is deducted
+// from it and the rest is burned.
+// Remaining accounts: with a partner, the token accounts of the partner shares in config order,
+// then the transfer hook accounts of the mint forwarded to transfer_checked.
+pub fn bridge_and_deposit_for_burn<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BridgeAndDepositForBurnContext<'info>>,
+    params: &BridgeAndDepositForBurnParams,
+) -> Result<()> {
+    // The fee tier is selected by the rolling volume before this transfer, the burn records it
+    let fee_tier = ctx.accounts.cctp.fee_tier()?;
+    let fee = ctx
+        .accounts
+        .cctp
+        .bridge_config
+        .get_tiered_fee_amount(params.amount, fee_tier)?;
+
+    let (partner_accounts, transfer_hook_accounts) = partner_fee::split_remaining_accounts(
+        ctx.accounts.partner_config.as_deref().map(|c| &**c),
+        params.partner_id,
+        ctx.remaining_accounts,
+    )?;
+    let fee_transfer = FeeTransfer {
+        token_program: ctx.accounts.cctp.token_program.to_account_info(),
+        from: ctx.accounts.cctp.user_usdc.to_account_info(),
+        mint: ctx.accounts.cctp.burn_token_mint.to_account_info(),
+        authority: ctx.accounts.cctp.user.to_account_info(),
+        decimals: ctx.accounts.cctp.burn_token_mint.decimals,
+        transfer_hook_accounts,
+    };
+
+    // Pay the partner shares first, the fee vault gets the rest of the fee
+    let partner_payments = match &ctx.accounts.partner_config {
+        Some(partner_config) => partner_fee::pay_partner_shares(
+            partner_config,
+            &mut ctx.accounts.fee_ledger,
+            &fee_transfer,
+            partner_accounts,
+            fee,
+        )?,
+        None => Vec::new(),
+    };
+    let vault_fee = fee - partner_fee::total_sent(&partner_payments);
+
+    // Transfer fee to the fee vault, burn mints are SPL Token mints without transfer fees
+    token_transfer::transfer_checked(
+        fee_transfer.context(ctx.accounts.fee_vault.to_account_info()),
+        vault_fee,
+        fee_transfer.decimals,
+    )?;
+    ctx.accounts
+        .fee_ledger
+        .record_collected(Some(params.destination_domain), vault_fee)?;
+
+    let depositor = ctx.accounts.cctp.user.key();
+    let mint = ctx.accounts.cctp.burn_token_mint.key();
+    if let Some(partner_id) = params.partner_id {
+        for payment in &partner_payments {
+            emit_cpi!(payment.event(partner_id, depositor, mint, Some(params.destination_domain)));
+        }
+    }
+
+    emit_cpi!(BridgeFeeCollected {
+        depositor,
+        mint,
+        amount: params.amount,
+        fee: vault_fee,
+        fee_tier: Some(fee_tier),
+        destination_domain: Some(params.destination_domain),
+    });
+
+    // Move the remaining amount into the program owned staging account
+    let burn_amount = params.amount - fee;
+    token_transfer::transfer_checked(
+        fee_transfer.context(ctx.accounts.staging_usdc.to_account_info()),
+        burn_amount,
+        fee_transfer.decimals,
+    )?;
+
+    // Domain bounds are checked by the burn on the amount actually burned
+    let staged = StagedBurn {
+        integration_authority: ctx.accounts.integration_authority.to_account_info(),
+        staging_usdc: ctx.accounts.staging_usdc.to_account_info(),
+        denylist_account: ctx.accounts.authority_denylist_account.to_account_info(),
+        authority_bump: ctx.accounts.cctp.bridge_config.authority_bump,
+    };
+    let cpi_params = ctx.accounts.cctp.burn_staged(
+        &ctx.bumps.cctp,
+        &DepositForBurnCctpParams {
+            amount: burn_amount,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: params.destination_caller,
+            max_fee: params.max_fee,
+            min_finality_threshold: params.min_finality_threshold,
+        },
+        EventRentPayer::Pool,
+        staged,
+    )?;
+    ctx.accounts.cctp.transfer_record.integration_fee = fee;
+
+    emit_cpi!(CctpDepositInitiated {
+        fee_tier: Some(fee_tier),
+        ..ctx.accounts.cctp.deposit_initiated(&cpi_params, fee)
+    });
+
+    msg!(
+        "Collected integration fee {} and burned {} via CCTP",
+        fee,
+        burn_amount
+    );
+    Ok(())
+}
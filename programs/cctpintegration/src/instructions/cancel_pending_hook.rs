@@ -0,0 +1,115 @@
+//! CancelPendingHook instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::PendingHookCancelled,
+        state::{BridgeConfig, PendingHook},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{transfer, Token, TokenAccount, Transfer},
+};
+
+// Instruction accounts
+// A pending hook can only run while its registration exists. Once the hook is unregistered,
+// the owner moves the reserved amount out of inbound_usdc and releases the reservation.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelPendingHookContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, owns inbound_usdc
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"inbound",
+            bridge_config.usdc_mint.as_ref()
+        ],
+        bump = bridge_config.inbound_bump
+    )]
+    pub inbound_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = rent_payer,
+        close = rent_payer
+    )]
+    pub pending_hook: Box<Account<'info, PendingHook>>,
+
+    /// CHECK: original payer of the pending hook rent, checked against pending_hook
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// CHECK: registration PDA of pending_hook.hook_id, must be closed
+    #[account(
+        seeds = [b"hook_registration".as_ref(), &[pending_hook.hook_id]],
+        bump,
+        constraint = hook_registration.data_is_empty() @ ErrorCode::HookStillRegistered
+    )]
+    pub hook_registration: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == bridge_config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Instruction handler
+pub fn cancel_pending_hook(ctx: Context<CancelPendingHookContext>) -> Result<()> {
+    let pending_hook = ctx.accounts.pending_hook.as_ref();
+    let (hook_id, source_domain, nonce, amount) = (
+        pending_hook.hook_id,
+        pending_hook.source_domain,
+        pending_hook.nonce,
+        pending_hook.amount,
+    );
+
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    bridge_config.inbound_reserved = bridge_config.inbound_reserved.saturating_sub(amount);
+
+    let authority_seeds: &[&[&[u8]]] = &[&[
+        b"integration_authority",
+        &[ctx.accounts.bridge_config.authority_bump],
+    ]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.inbound_usdc.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.integration_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        amount,
+    )?;
+
+    emit_cpi!(PendingHookCancelled {
+        hook_id,
+        source_domain,
+        nonce,
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
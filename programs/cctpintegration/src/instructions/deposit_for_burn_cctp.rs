@@ -0,0 +1,608 @@
+//! DepositForBurnCctp instruction handler
+
+use {
+    crate::{
+        cctp,
+        error::ErrorCode,
+        events::CctpDepositInitiated,
+        fee_quote::FeeQuote,
+        state::{
+            BlockedAddress, BridgeConfig, DomainConfig, FeeLedger, TransferRecord, TransferStatus,
+            UsedQuote, UserStats, UserTransferCounter,
+        },
+        token_transfer,
+    },
+    anchor_lang::{prelude::*, solana_program::sysvar},
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+    message_transmitter_v2::{
+        events::MessageSent, program::MessageTransmitterV2, state::MessageTransmitter,
+    },
+    token_messenger_minter_v2::{
+        cpi::{accounts::DepositForBurnContext, deposit_for_burn, deposit_for_burn_with_hook},
+        program::TokenMessengerMinterV2,
+        token_messenger_v2::{
+            burn_message::BurnMessage,
+            instructions::{DepositForBurnParams, DepositForBurnWithHookParams},
+            state::{RemoteTokenMessenger, TokenMessenger},
+        },
+        token_minter_v2::state::{LocalToken, TokenMinter},
+    },
+};
+
+// Instruction accounts
+// Shared by the deposit instructions, which add event_cpi on their own accounts
+#[derive(Accounts)]
+pub struct CctpTransfer<'info> {
+    // Owner of user_usdc, only signs as the token owner
+    #[account()]
+    pub user: Signer<'info>,
+
+    // Pays the transfer record rent, and the event account rent unless event_rent_pool pays it:
+    // the user, or the fee-payer service for sponsored deposits
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = user_usdc.mint == burn_token_mint.key() @ ErrorCode::InvalidMint
+    )]
+    pub user_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: program-funded event_rent_pool PDA, system owned, pays MessageSent account rent
+    /// of deposits that charge an integration fee
+    #[account(
+        mut,
+        seeds = [b"event_rent_pool"],
+        bump
+    )]
+    pub event_rent_pool: UncheckedAccount<'info>,
+
+    /// CHECK: empty PDA, used by TokenMessengerMinter to sign sendMessage
+    #[account(
+        constraint = sender_authority_pda.key() == cctp::sender_authority_address(token_messenger.authority_bump)? @ ErrorCode::InvalidSenderAuthority
+    )]
+    pub sender_authority_pda: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub message_transmitter: Box<Account<'info, MessageTransmitter>>,
+
+    #[account(
+        seeds = [b"token_messenger"],
+        bump,
+        seeds::program = token_messenger_minter_program
+    )]
+    pub token_messenger: Box<Account<'info, TokenMessenger>>,
+
+    #[account(
+        seeds = [
+            b"remote_token_messenger",
+            remote_token_messenger.domain.to_string().as_bytes()
+        ],
+        bump,
+        seeds::program = token_messenger_minter_program
+    )]
+    pub remote_token_messenger: Box<Account<'info, RemoteTokenMessenger>>,
+
+    #[account(
+        seeds = [b"domain_config".as_ref(), &domain_config.domain.to_le_bytes()],
+        bump = domain_config.bump
+    )]
+    pub domain_config: Box<Account<'info, DomainConfig>>,
+
+    #[account()]
+    pub token_minter: Box<Account<'info, TokenMinter>>,
+
+    #[account(
+        mut,
+        constraint = local_token.key() == cctp::local_token_address(&burn_token_mint.key(), local_token.bump)? @ ErrorCode::InvalidLocalToken
+    )]
+    pub local_token: Box<Account<'info, LocalToken>>,
+
+    // TokenMessengerMinter burns through SPL Token only, Token-2022 mints are rejected up front
+    #[account(
+        mut,
+        owner = anchor_spl::token::ID @ ErrorCode::UnsupportedBurnMint
+    )]
+    pub burn_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Account to store MessageSent event data in. Any non-PDA uninitialized address.
+    #[account(mut)]
+    pub message_sent_event_data: Signer<'info>,
+
+    pub message_transmitter_program: Program<'info, MessageTransmitterV2>,
+
+    pub token_messenger_minter_program: Program<'info, TokenMessengerMinterV2>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: TokenMessengerMinter event authority
+    #[account(
+        constraint = token_messenger_minter_event_authority.key() == cctp::event_authority_address() @ ErrorCode::InvalidEventAuthority
+    )]
+    pub token_messenger_minter_event_authority: UncheckedAccount<'info>,
+
+    /// CHECK: denylist PDA of the burn token owner, checked by TokenMessengerMinter.
+    /// Checked here as well so that denylisted users get a wrapper error instead of a failed CPI
+    #[account(
+        constraint = denylist_account.key() == cctp::denylist_account_address(&user.key()) @ ErrorCode::InvalidDenylistAccount,
+        constraint = denylist_account.data_is_empty() @ ErrorCode::DenylistedAccount
+    )]
+    pub denylist_account: UncheckedAccount<'info>,
+
+    /// CHECK: BlockedAddress PDA of the user, must not exist
+    #[account(
+        seeds = [b"blocked_address", user.key().as_ref()],
+        bump,
+        constraint = blocked_user.data_is_empty() @ ErrorCode::AddressBlocked
+    )]
+    pub blocked_user: UncheckedAccount<'info>,
+
+    /// CHECK: BlockedAddress PDA of the mint recipient, must not exist. Checked by
+    /// validate_deposit against the mint recipient actually sent to TokenMessengerMinter
+    #[account()]
+    pub blocked_mint_recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserTransferCounter::DISCRIMINATOR.len() + UserTransferCounter::INIT_SPACE,
+        seeds = [b"user_transfer_counter", user.key().as_ref()],
+        bump
+    )]
+    pub user_transfer_counter: Box<Account<'info, UserTransferCounter>>,
+
+    // Rolling volume of the user, every burn counts towards the fee tier
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE,
+        seeds = [b"user_stats", user.key().as_ref()],
+        bump
+    )]
+    pub user_stats: Box<Account<'info, UserStats>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = TransferRecord::DISCRIMINATOR.len() + TransferRecord::INIT_SPACE,
+        seeds = [
+            b"transfer_record",
+            user.key().as_ref(),
+            &user_transfer_counter.next_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub transfer_record: Box<Account<'info, TransferRecord>>,
+}
+
+// With a fee quote, the transaction must carry an Ed25519 program instruction signing it
+// right before this instruction.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: DepositForBurnCctpParams, quote: Option<FeeQuote>)]
+pub struct DepositForBurnCctpContext<'info> {
+    pub cctp: CctpTransfer<'info>,
+
+    // Fee ledger and fee vault of the quoted fee, None without a quote
+    #[account(
+        mut,
+        seeds = [b"fee_ledger", cctp.bridge_config.usdc_mint.as_ref()],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Option<Box<Account<'info, FeeLedger>>>,
+
+    #[account(
+        mut,
+        address = fee_ledger.as_ref().map_or(Pubkey::default(), |ledger| ledger.fee_vault) @ ErrorCode::InvalidFeeAccount
+    )]
+    pub fee_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // Marks the fee quote used, None without a quote
+    #[account(
+        init,
+        payer = cctp.payer,
+        space = UsedQuote::DISCRIMINATOR.len() + UsedQuote::INIT_SPACE,
+        seeds = [
+            b"used_quote".as_ref(),
+            quote.as_ref().map_or(&[0u8; 32][..], |quote| quote.quote_id.as_ref())
+        ],
+        bump
+    )]
+    pub used_quote: Option<Box<Account<'info, UsedQuote>>>,
+
+    /// CHECK: instructions sysvar, read to find the Ed25519 instruction. None without a quote
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account paying the MessageSent event account rent of a burn
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventRentPayer {
+    // event_rent_pool, only for deposits that charge an integration fee. Falls back to the
+    // payer when the pool is short or the user reached its cap of pending pool-paid accounts
+    Pool,
+    // CctpTransfer payer
+    Payer,
+}
+
+/// Staging token account burned instead of user_usdc, integration_authority signs as its owner
+pub struct StagedBurn<'info> {
+    pub integration_authority: AccountInfo<'info>,
+    pub staging_usdc: AccountInfo<'info>,
+    // denylist PDA of integration_authority, checked by TokenMessengerMinter
+    pub denylist_account: AccountInfo<'info>,
+    pub authority_bump: u8,
+}
+
+impl<'info> CctpTransfer<'info> {
+    /// Accounts for the TokenMessengerMinter deposit_for_burn and deposit_for_burn_with_hook CPIs
+    pub fn deposit_for_burn_accounts(&self) -> DepositForBurnContext<'info> {
+        DepositForBurnContext {
+            owner: self.user.to_account_info(),
+            event_rent_payer: self.payer.to_account_info(),
+            sender_authority_pda: self.sender_authority_pda.to_account_info(),
+            burn_token_account: self.user_usdc.to_account_info(),
+            denylist_account: self.denylist_account.to_account_info(),
+            message_transmitter: self.message_transmitter.to_account_info(),
+            token_messenger: self.token_messenger.to_account_info(),
+            remote_token_messenger: self.remote_token_messenger.to_account_info(),
+            token_minter: self.token_minter.to_account_info(),
+            local_token: self.local_token.to_account_info(),
+            burn_token_mint: self.burn_token_mint.to_account_info(),
+            message_sent_event_data: self.message_sent_event_data.to_account_info(),
+            message_transmitter_program: self.message_transmitter_program.to_account_info(),
+            token_messenger_minter_program: self.token_messenger_minter_program.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+            system_program: self.system_program.to_account_info(),
+            event_authority: self
+                .token_messenger_minter_event_authority
+                .to_account_info(),
+            program: self.token_messenger_minter_program.to_account_info(),
+        }
+    }
+
+    /// Validates params and fills the unset finality threshold and max fee from the
+    /// destination domain defaults, returns the params to pass to TokenMessengerMinter
+    pub fn deposit_params(
+        &self,
+        params: &DepositForBurnCctpParams,
+    ) -> Result<DepositForBurnParams> {
+        let deposit_params = DepositForBurnParams {
+            amount: params.amount,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: params.destination_caller,
+            max_fee: self.domain_config.max_fee(params.max_fee),
+            min_finality_threshold: self
+                .domain_config
+                .min_finality_threshold(params.min_finality_threshold),
+        };
+        validate_deposit(
+            &self.remote_token_messenger,
+            &self.domain_config,
+            &self.blocked_mint_recipient,
+            &deposit_params,
+        )?;
+
+        Ok(deposit_params)
+    }
+
+    /// Burns params.amount from user_usdc and records the transfer, returns the params passed to CCTP
+    pub fn burn(
+        &mut self,
+        bumps: &CctpTransferBumps,
+        params: &DepositForBurnCctpParams,
+        rent_payer: EventRentPayer,
+    ) -> Result<DepositForBurnParams> {
+        self.burn_from(bumps, params, rent_payer, None)
+    }
+
+    /// Burns params.amount from the staging account and records the transfer,
+    /// returns the params passed to CCTP
+    pub fn burn_staged(
+        &mut self,
+        bumps: &CctpTransferBumps,
+        params: &DepositForBurnCctpParams,
+        rent_payer: EventRentPayer,
+        staged: StagedBurn<'info>,
+    ) -> Result<DepositForBurnParams> {
+        self.burn_from(bumps, params, rent_payer, Some(staged))
+    }
+
+    fn burn_from(
+        &mut self,
+        bumps: &CctpTransferBumps,
+        params: &DepositForBurnCctpParams,
+        rent_payer: EventRentPayer,
+        staged: Option<StagedBurn<'info>>,
+    ) -> Result<DepositForBurnParams> {
+        let cpi_params = self.deposit_params(params)?;
+        // Without a hook payload the destination wrapper cannot forward out of its inbound account
+        require!(
+            !self
+                .domain_config
+                .is_inbound_recipient(&cpi_params.mint_recipient),
+            ErrorCode::InvalidRecipient
+        );
+
+        let event_rent_seeds: &[&[u8]] = &[b"event_rent_pool", &[bumps.event_rent_pool]];
+        let authority_bump = [staged.as_ref().map_or(0, |staged| staged.authority_bump)];
+        let authority_seeds: &[&[u8]] = &[b"integration_authority", &authority_bump];
+        let mut signer_seeds = Vec::with_capacity(2);
+
+        let mut cpi_accounts = self.deposit_for_burn_accounts();
+        if rent_payer == EventRentPayer::Pool && self.subsidize_event_rent()? {
+            cpi_accounts.event_rent_payer = self.event_rent_pool.to_account_info();
+            signer_seeds.push(event_rent_seeds);
+        }
+        if let Some(staged) = staged {
+            cpi_accounts.owner = staged.integration_authority;
+            cpi_accounts.burn_token_account = staged.staging_usdc;
+            cpi_accounts.denylist_account = staged.denylist_account;
+            signer_seeds.push(authority_seeds);
+        }
+
+        let cpi_program = self.token_messenger_minter_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, &signer_seeds);
+        deposit_for_burn(cpi_ctx, cpi_params.clone())?;
+
+        self.record_transfer(bumps, &cpi_params)?;
+        self.record_volume(bumps, cpi_params.amount)?;
+
+        Ok(cpi_params)
+    }
+
+    /// Burns params.amount from user_usdc with hook_data attached to the burn message
+    /// and records the transfer, returns the params passed to CCTP
+    pub fn burn_with_hook(
+        &mut self,
+        bumps: &CctpTransferBumps,
+        params: &DepositForBurnCctpParams,
+        hook_data: Vec<u8>,
+    ) -> Result<DepositForBurnParams> {
+        let cpi_params = self.deposit_params(params)?;
+
+        // Hook deposits charge no integration fee, payer pays the event account rent
+        let cpi_accounts = self.deposit_for_burn_accounts();
+        let cpi_program = self.token_messenger_minter_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        deposit_for_burn_with_hook(
+            cpi_ctx,
+            DepositForBurnWithHookParams {
+                amount: cpi_params.amount,
+                destination_domain: cpi_params.destination_domain,
+                mint_recipient: cpi_params.mint_recipient,
+                destination_caller: cpi_params.destination_caller,
+                max_fee: cpi_params.max_fee,
+                min_finality_threshold: cpi_params.min_finality_threshold,
+                hook_data,
+            },
+        )?;
+
+        self.record_transfer(bumps, &cpi_params)?;
+        self.record_volume(bumps, cpi_params.amount)?;
+
+        Ok(cpi_params)
+    }
+
+    /// Lets event_rent_pool pay the event account of this deposit if the pool stays rent exempt
+    /// after paying it and the user is below the cap of pending pool-paid event accounts.
+    /// Returns false otherwise, payer then pays the rent as for deposits without a fee
+    fn subsidize_event_rent(&mut self) -> Result<bool> {
+        let rent = Rent::get()?;
+        let event_rent = rent.minimum_balance(MessageSent::len(BurnMessage::serialized_len(0)?)?);
+
+        Ok(self.user_transfer_counter.start_subsidy(
+            self.event_rent_pool.lamports(),
+            rent.minimum_balance(0) + event_rent,
+        ))
+    }
+
+    /// Returns the CctpDepositInitiated event of a deposit burned with `params`
+    pub fn deposit_initiated(
+        &self,
+        params: &DepositForBurnParams,
+        integration_fee: u64,
+    ) -> CctpDepositInitiated {
+        CctpDepositInitiated {
+            depositor: self.user.key(),
+            burn_token: self.burn_token_mint.key(),
+            amount: params.amount,
+            integration_fee,
+            fee_tier: None,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: params.destination_caller,
+            max_fee: params.max_fee,
+            min_finality_threshold: params.min_finality_threshold,
+            message_sent_event_data: self.message_sent_event_data.key(),
+        }
+    }
+
+    /// Returns the fee tier of the rolling volume of the user before this deposit
+    pub fn fee_tier(&self) -> Result<u8> {
+        let volume = self.user_stats.rolling_volume(Clock::get()?.unix_timestamp);
+        Ok(self.bridge_config.get_fee_tier(volume))
+    }
+
+    /// Adds the burned `amount` to the rolling volume of the user
+    fn record_volume(&mut self, bumps: &CctpTransferBumps, amount: u64) -> Result<()> {
+        self.user_stats.record(
+            self.user.key(),
+            bumps.user_stats,
+            Clock::get()?.unix_timestamp,
+            amount,
+        );
+        Ok(())
+    }
+
+    /// Creates the TransferRecord of this deposit, callers charging an integration fee set it afterwards
+    pub fn record_transfer(
+        &mut self,
+        bumps: &CctpTransferBumps,
+        params: &DepositForBurnParams,
+    ) -> Result<()> {
+        let index = self
+            .user_transfer_counter
+            .next(self.user.key(), bumps.user_transfer_counter)?;
+
+        self.transfer_record.set_inner(TransferRecord {
+            user: self.user.key(),
+            index,
+            status: TransferStatus::Pending,
+            amount: params.amount,
+            integration_fee: 0,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            message_sent_event_data: self.message_sent_event_data.key(),
+            min_finality_threshold: params.min_finality_threshold,
+            created_at: Clock::get()?.unix_timestamp,
+            attested_at: 0,
+            reclaimed_at: 0,
+            bump: bumps.transfer_record,
+        });
+
+        Ok(())
+    }
+}
+
+/// Checks the params sent to TokenMessengerMinter against the destination domain accounts
+pub fn validate_deposit(
+    remote_token_messenger: &RemoteTokenMessenger,
+    domain_config: &DomainConfig,
+    blocked_mint_recipient: &AccountInfo,
+    params: &DepositForBurnParams,
+) -> Result<()> {
+    require_eq!(
+        remote_token_messenger.domain,
+        params.destination_domain,
+        ErrorCode::InvalidDestinationDomain
+    );
+    require_eq!(
+        domain_config.domain,
+        params.destination_domain,
+        ErrorCode::InvalidDestinationDomain
+    );
+    require!(domain_config.enabled, ErrorCode::InvalidDestinationDomain);
+    require!(
+        domain_config.is_amount_allowed(params.amount),
+        ErrorCode::InvalidAmount
+    );
+    require!(
+        domain_config
+            .domain_type
+            .is_valid_mint_recipient(&params.mint_recipient),
+        ErrorCode::InvalidRecipient
+    );
+    require!(
+        domain_config
+            .domain_type
+            .is_valid_destination_caller(&params.destination_caller),
+        ErrorCode::InvalidDestinationCaller
+    );
+    require!(
+        domain_config.is_finality_allowed(params.min_finality_threshold, params.amount),
+        ErrorCode::FastTransferCapExceeded
+    );
+    BlockedAddress::require_not_blocked(blocked_mint_recipient, &params.mint_recipient)
+}
+
+// Instruction parameters
+// Unset max_fee and min_finality_threshold use the defaults of the destination domain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DepositForBurnCctpParams {
+    pub amount: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    // For no destination caller, use Pubkey::default()
+    pub destination_caller: Pubkey,
+    pub max_fee: Option<u64>,
+    pub min_finality_threshold: Option<u32>,
+}
+
+// Instruction handler
+// With a fee quote, quote.fee is charged on top of the burned params.amount and quote.amount
+// is their sum, as the total taken from the user in a quoted bridge. event_rent_pool then pays the event account rent. Remaining accounts are forwarded to
+// transfer_checked as the transfer hook accounts of the mint.
+pub fn deposit_for_burn_cctp<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DepositForBurnCctpContext<'info>>,
+    params: &DepositForBurnCctpParams,
+    quote: Option<FeeQuote>,
+) -> Result<()> {
+    let Some(quote) = quote else {
+        require!(ctx.accounts.used_quote.is_none(), ErrorCode::InvalidQuote);
+        let cpi_params = ctx
+            .accounts
+            .cctp
+            .burn(&ctx.bumps.cctp, params, EventRentPayer::Payer)?;
+
+        emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, 0));
+
+        msg!("CCTP deposit_for_burn executed successfully");
+        return Ok(());
+    };
+
+    let total_amount = params
+        .amount
+        .checked_add(quote.fee)
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+    quote.validate(
+        &ctx.accounts.cctp.user.key(),
+        total_amount,
+        Some(params.destination_domain),
+    )?;
+    quote.redeem(
+        ctx.accounts.used_quote.as_deref_mut(),
+        ctx.bumps.used_quote,
+        ctx.accounts.instructions_sysvar.as_deref(),
+        &ctx.accounts.cctp.bridge_config,
+    )?;
+
+    let (Some(fee_ledger), Some(fee_vault)) = (
+        ctx.accounts.fee_ledger.as_deref_mut(),
+        ctx.accounts.fee_vault.as_deref(),
+    ) else {
+        return err!(ErrorCode::InvalidFeeAccount);
+    };
+    // Burn mints are SPL Token mints, the fee vault is credited the full quoted fee
+    token_transfer::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.cctp.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.cctp.user_usdc.to_account_info(),
+                mint: ctx.accounts.cctp.burn_token_mint.to_account_info(),
+                to: fee_vault.to_account_info(),
+                authority: ctx.accounts.cctp.user.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        quote.fee,
+        ctx.accounts.cctp.burn_token_mint.decimals,
+    )?;
+    fee_ledger.record_collected(Some(params.destination_domain), quote.fee)?;
+
+    let cpi_params = ctx
+        .accounts
+        .cctp
+        .burn(&ctx.bumps.cctp, params, EventRentPayer::Pool)?;
+    ctx.accounts.cctp.transfer_record.integration_fee = quote.fee;
+
+    emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, quote.fee));
+
+    msg!(
+        "CCTP deposit_for_burn executed successfully with quoted fee {}",
+        quote.fee
+    );
+    Ok(())
+}
@@ -0,0 +1,103 @@
+//! DepositForBurnRelayedCctp instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        hook_payload::{HookKind, HookPayload},
+        instructions::deposit_for_burn_cctp::*,
+        state::DomainType,
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositForBurnRelayedCctpContext<'info> {
+    pub cctp: CctpTransfer<'info>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DepositForBurnRelayedCctpParams {
+    pub amount: u64,
+    pub destination_domain: u32,
+    // Inbound token account of cctpintegration on the destination
+    pub mint_recipient: Pubkey,
+    // Unset max_fee and min_finality_threshold use the defaults of the destination domain
+    pub max_fee: Option<u64>,
+    pub min_finality_threshold: Option<u32>,
+    // Owner of the token account that receives the transfer on the destination
+    pub recipient: Pubkey,
+    // Paid out of the minted amount to the relayer completing the transfer
+    pub relayer_fee: u64,
+    pub client_reference: [u8; 32],
+}
+
+/// Returns the relayer_authority PDA, the same address on every chain cctpintegration is deployed on
+pub fn relayer_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"relayer_authority"], &crate::ID).0
+}
+
+// Instruction handler
+pub fn deposit_for_burn_relayed_cctp(
+    ctx: Context<DepositForBurnRelayedCctpContext>,
+    params: &DepositForBurnRelayedCctpParams,
+) -> Result<()> {
+    // relayer_authority only exists on Solana deployments of cctpintegration, it cannot
+    // receive the message on EVM or other domains
+    require!(
+        ctx.accounts.cctp.domain_config.domain_type == DomainType::Solana,
+        ErrorCode::InvalidDestinationDomain
+    );
+
+    // The relayer fee must still be payable if the full max_fee is charged by CCTP
+    let max_fee = ctx.accounts.cctp.domain_config.max_fee(params.max_fee);
+    require_gt!(
+        params.amount.saturating_sub(max_fee),
+        params.relayer_fee,
+        ErrorCode::InvalidFee
+    );
+    require!(
+        ctx.accounts
+            .cctp
+            .domain_config
+            .is_inbound_recipient(&params.mint_recipient),
+        ErrorCode::InvalidRecipient
+    );
+    require_keys_neq!(
+        params.recipient,
+        Pubkey::default(),
+        ErrorCode::InvalidRecipient
+    );
+
+    let hook_data = HookPayload::format_payload(
+        HookKind::Forward,
+        &params.recipient,
+        &params.client_reference,
+        params.relayer_fee,
+        &[],
+    )?;
+
+    // Relayed transfers always use relayer_authority as the destination caller
+    let cpi_params = ctx.accounts.cctp.burn_with_hook(
+        &ctx.bumps.cctp,
+        &DepositForBurnCctpParams {
+            amount: params.amount,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: relayer_authority_address(),
+            max_fee: Some(max_fee),
+            min_finality_threshold: params.min_finality_threshold,
+        },
+        hook_data,
+    )?;
+
+    emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, 0));
+
+    msg!(
+        "CCTP relayed deposit_for_burn executed successfully, relayer fee {}",
+        params.relayer_fee
+    );
+    Ok(())
+}
@@ -0,0 +1,142 @@
+//! DepositForBurnSponsoredCctp instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        instructions::deposit_for_burn_cctp::*,
+        state::{
+            BridgeConfig, FeeLedger, SponsorConfig, TransferRecord, UserStats, UserTransferCounter,
+        },
+        token_transfer,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{TokenAccount, TransferChecked},
+};
+
+// Instruction accounts
+// The fee-payer service signs as cctp.payer and pays every SOL cost of the transaction,
+// the user only signs as the owner of cctp.user_usdc.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositForBurnSponsoredCctpContext<'info> {
+    #[account(
+        seeds = [b"sponsor_config"],
+        bump = sponsor_config.bump,
+        constraint = sponsor_config.enabled @ ErrorCode::InvalidSponsor
+    )]
+    pub sponsor_config: Box<Account<'info, SponsorConfig>>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_ledger", bridge_config.usdc_mint.as_ref()],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Box<Account<'info, FeeLedger>>,
+
+    #[account(
+        mut,
+        address = fee_ledger.fee_vault @ ErrorCode::InvalidFeeAccount
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub cctp: CctpTransfer<'info>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DepositForBurnSponsoredCctpParams {
+    pub amount: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    // For no destination caller, use Pubkey::default()
+    pub destination_caller: Pubkey,
+    // Unset max_fee and min_finality_threshold use the defaults of the destination domain
+    pub max_fee: Option<u64>,
+    pub min_finality_threshold: Option<u32>,
+    // Largest USDC surcharge the user accepts, charged on top of amount
+    pub max_surcharge: u64,
+}
+
+// Instruction handler
+pub fn deposit_for_burn_sponsored_cctp<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DepositForBurnSponsoredCctpContext<'info>>,
+    params: &DepositForBurnSponsoredCctpParams,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.cctp.payer.key(),
+        ctx.accounts.sponsor_config.fee_payer,
+        ErrorCode::InvalidSponsor
+    );
+
+    // The surcharge covers the rent of transfer_record and a new user_transfer_counter and
+    // user_stats, created by the fee payer and never closed. The MessageSent event account rent is not charged:
+    // the fee payer pays it and gets it back when it reclaims the event account
+    let rent = Rent::get()?;
+    let mut account_rent =
+        rent.minimum_balance(TransferRecord::DISCRIMINATOR.len() + TransferRecord::INIT_SPACE);
+    if ctx.accounts.cctp.user_transfer_counter.user == Pubkey::default() {
+        account_rent += rent.minimum_balance(
+            UserTransferCounter::DISCRIMINATOR.len() + UserTransferCounter::INIT_SPACE,
+        );
+    }
+    if ctx.accounts.cctp.user_stats.user == Pubkey::default() {
+        account_rent +=
+            rent.minimum_balance(UserStats::DISCRIMINATOR.len() + UserStats::INIT_SPACE);
+    }
+
+    let cpi_params = ctx.accounts.cctp.burn(
+        &ctx.bumps.cctp,
+        &DepositForBurnCctpParams {
+            amount: params.amount,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: params.destination_caller,
+            max_fee: params.max_fee,
+            min_finality_threshold: params.min_finality_threshold,
+        },
+        EventRentPayer::Payer,
+    )?;
+
+    let surcharge = ctx
+        .accounts
+        .sponsor_config
+        .get_surcharge_amount(account_rent)?;
+    require_gte!(
+        params.max_surcharge,
+        surcharge,
+        ErrorCode::SurchargeExceeded
+    );
+
+    // Remaining accounts are forwarded to transfer_checked as the transfer hook accounts of the mint
+    token_transfer::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.cctp.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.cctp.user_usdc.to_account_info(),
+                mint: ctx.accounts.cctp.burn_token_mint.to_account_info(),
+                to: ctx.accounts.fee_vault.to_account_info(),
+                authority: ctx.accounts.cctp.user.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        surcharge,
+        ctx.accounts.cctp.burn_token_mint.decimals,
+    )?;
+    ctx.accounts
+        .fee_ledger
+        .record_collected(Some(params.destination_domain), surcharge)?;
+
+    emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, surcharge));
+
+    msg!(
+        "CCTP sponsored deposit_for_burn executed successfully, surcharge {}",
+        surcharge
+    );
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//! DepositForBurnWithHookCctp instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        hook_payload::{HookKind, HookPayload},
+        instructions::deposit_for_burn_cctp::*,
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositForBurnWithHookCctpContext<'info> {
+    pub cctp: CctpTransfer<'info>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DepositForBurnWithHookCctpParams {
+    pub amount: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    // For no destination caller, use Pubkey::default(). Must be integration_authority
+    // when mint_recipient is the inbound account of the destination domain
+    pub destination_caller: Pubkey,
+    // Unset max_fee and min_finality_threshold use the defaults of the destination domain
+    pub max_fee: Option<u64>,
+    pub min_finality_threshold: Option<u32>,
+    pub hook_kind: HookKind,
+    // Destination address the hook acts on, EVM addresses are left-padded to 32 bytes
+    pub hook_target: Pubkey,
+    pub calldata: Vec<u8>,
+    pub client_reference: [u8; 32],
+}
+
+/// Returns the integration_authority PDA, the same address on every chain cctpintegration is deployed on
+pub fn integration_authority_address() -> Pubkey {
+    Pubkey::find_program_address(&[b"integration_authority"], &crate::ID).0
+}
+
+// Instruction handler
+pub fn deposit_for_burn_with_hook_cctp(
+    ctx: Context<DepositForBurnWithHookCctpContext>,
+    params: &DepositForBurnWithHookCctpParams,
+) -> Result<()> {
+    // Hooks minted into the inbound account are only recorded and executed when the destination
+    // wrapper receives the message, anyone else receiving it would strand the funds there
+    if ctx
+        .accounts
+        .cctp
+        .domain_config
+        .is_inbound_recipient(&params.mint_recipient)
+    {
+        require_keys_eq!(
+            params.destination_caller,
+            integration_authority_address(),
+            ErrorCode::InvalidDestinationCaller
+        );
+    }
+
+    let hook_data = HookPayload::format_payload(
+        params.hook_kind,
+        &params.hook_target,
+        &params.client_reference,
+        0,
+        &params.calldata,
+    )?;
+
+    let cpi_params = ctx.accounts.cctp.burn_with_hook(
+        &ctx.bumps.cctp,
+        &DepositForBurnCctpParams {
+            amount: params.amount,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: params.destination_caller,
+            max_fee: params.max_fee,
+            min_finality_threshold: params.min_finality_threshold,
+        },
+        hook_data,
+    )?;
+
+    emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, 0));
+
+    msg!("CCTP deposit_for_burn_with_hook executed successfully");
+    Ok(())
+}
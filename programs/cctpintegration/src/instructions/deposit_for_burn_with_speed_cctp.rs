@@ -0,0 +1,64 @@
+//! DepositForBurnWithSpeedCctp instruction handler
+
+use {
+    crate::{instructions::deposit_for_burn_cctp::*, state::TransferSpeed},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositForBurnWithSpeedCctpContext<'info> {
+    pub cctp: CctpTransfer<'info>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DepositForBurnWithSpeedCctpParams {
+    pub amount: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    // For no destination caller, use Pubkey::default()
+    pub destination_caller: Pubkey,
+    pub speed: TransferSpeed,
+}
+
+// Instruction handler
+// The finality threshold is set from params.speed and max_fee is the TokenMessenger
+// min fee of the amount plus the max_fee_buffer of the destination domain.
+pub fn deposit_for_burn_with_speed_cctp(
+    ctx: Context<DepositForBurnWithSpeedCctpContext>,
+    params: &DepositForBurnWithSpeedCctpParams,
+) -> Result<()> {
+    let min_fee_amount = ctx
+        .accounts
+        .cctp
+        .token_messenger
+        .get_min_fee_amount(params.amount)?;
+    let max_fee = ctx
+        .accounts
+        .cctp
+        .domain_config
+        .max_fee_with_buffer(min_fee_amount)?;
+
+    let cpi_params = ctx.accounts.cctp.burn(
+        &ctx.bumps.cctp,
+        &DepositForBurnCctpParams {
+            amount: params.amount,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: params.destination_caller,
+            max_fee: Some(max_fee),
+            min_finality_threshold: Some(params.speed.min_finality_threshold()),
+        },
+        EventRentPayer::Payer,
+    )?;
+
+    emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, 0));
+
+    msg!(
+        "CCTP deposit_for_burn executed successfully with {:?} speed",
+        params.speed
+    );
+    Ok(())
+}
@@ -0,0 +1,200 @@
+//! ExecuteHook instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::CctpTransferCompleted,
+        state::{BridgeConfig, HookRegistration, PendingHook},
+    },
+    anchor_lang::prelude::*,
+    anchor_lang::solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program,
+    },
+    anchor_spl::token::{transfer, Token, TokenAccount, Transfer},
+};
+
+/////////////////////////////////////////////////////////////////////////////
+/// IMPORTANT!
+/// This instruction makes an arbitrary CPI into the registered hook program.
+/// The hook program must never be this program (checked both here and in
+/// register_hook), and pending_hook is closed before the CPI so the same
+/// hook cannot be executed twice through reentrancy. integration_authority
+/// never signs the hook CPI, so the hook program cannot move inbound funds.
+/// /////////////////////////////////////////////////////////////////////////
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteHookContext<'info> {
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, owns inbound_usdc
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"inbound",
+            bridge_config.usdc_mint.as_ref()
+        ],
+        bump = bridge_config.inbound_bump
+    )]
+    pub inbound_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = rent_payer
+    )]
+    pub pending_hook: Box<Account<'info, PendingHook>>,
+
+    /// CHECK: original payer of the pending hook rent, checked against pending_hook
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"hook_registration".as_ref(), &[pending_hook.hook_id]],
+        bump = hook_registration.bump
+    )]
+    pub hook_registration: Box<Account<'info, HookRegistration>>,
+
+    #[account(
+        mut,
+        address = hook_registration.token_account @ ErrorCode::InvalidHookAccounts
+    )]
+    pub hook_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: registered hook program
+    #[account(
+        executable,
+        address = hook_registration.target_program @ ErrorCode::InvalidHookProgram,
+        constraint = hook_program.key() != crate::ID @ ErrorCode::InvalidHookProgram
+    )]
+    pub hook_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // remaining accounts: accounts passed to the hook program, as registered in hook_registration
+}
+
+// Instruction data passed to the hook program after the instruction discriminator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct HookExecutionParams {
+    pub amount: u64,
+    pub source_domain: u32,
+    pub nonce: [u8; 32],
+    pub hook_payload: Vec<u8>,
+}
+
+pub const HOOK_INSTRUCTION_NAME: &str = "global:on_cctp_hook";
+
+// Instruction handler
+pub fn execute_hook<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteHookContext<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .hook_registration
+            .validate_accounts(ctx.remaining_accounts),
+        ErrorCode::InvalidHookAccounts
+    );
+
+    let pending_hook = ctx.accounts.pending_hook.as_ref();
+    let hook_params = HookExecutionParams {
+        amount: pending_hook.amount,
+        source_domain: pending_hook.source_domain,
+        nonce: pending_hook.nonce,
+        hook_payload: pending_hook.hook_payload.clone(),
+    };
+
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    bridge_config.inbound_reserved = bridge_config
+        .inbound_reserved
+        .saturating_sub(hook_params.amount);
+
+    // Close pending_hook before any CPI so it cannot be executed again
+    ctx.accounts
+        .pending_hook
+        .close(ctx.accounts.rent_payer.to_account_info())?;
+
+    let authority_seeds: &[&[&[u8]]] = &[&[
+        b"integration_authority",
+        &[ctx.accounts.bridge_config.authority_bump],
+    ]];
+
+    // Hand the minted amount over to the hook program
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.inbound_usdc.to_account_info(),
+                to: ctx.accounts.hook_token_account.to_account_info(),
+                authority: ctx.accounts.integration_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        hook_params.amount,
+    )?;
+
+    let mut accounts = Vec::with_capacity(1 + ctx.remaining_accounts.len());
+    accounts.push(AccountMeta::new(
+        ctx.accounts.hook_token_account.key(),
+        false,
+    ));
+    for (acc, meta) in ctx
+        .remaining_accounts
+        .iter()
+        .zip(ctx.accounts.hook_registration.accounts.iter())
+    {
+        if meta.is_writable {
+            accounts.push(AccountMeta::new(acc.key(), false));
+        } else {
+            accounts.push(AccountMeta::new_readonly(acc.key(), false));
+        }
+    }
+
+    let mut data = Vec::with_capacity(8 + hook_params.hook_payload.len() + 48);
+    data.extend_from_slice(
+        &anchor_lang::solana_program::hash::hash(HOOK_INSTRUCTION_NAME.as_bytes()).to_bytes()[..8],
+    );
+    data.extend_from_slice(&hook_params.try_to_vec()?);
+
+    let instruction = Instruction {
+        program_id: ctx.accounts.hook_program.key(),
+        accounts,
+        data,
+    };
+
+    let mut account_infos = vec![
+        ctx.accounts.hook_token_account.to_account_info(),
+        ctx.accounts.hook_program.to_account_info(),
+    ];
+    account_infos.extend(ctx.remaining_accounts.iter().cloned());
+
+    program::invoke(&instruction, &account_infos)?;
+
+    emit_cpi!(CctpTransferCompleted {
+        source_domain: hook_params.source_domain,
+        nonce: hook_params.nonce,
+        mint_token: ctx.accounts.inbound_usdc.mint,
+        recipient_token_account: ctx.accounts.hook_token_account.key(),
+        amount: hook_params.amount,
+        relayer_fee: 0,
+    });
+
+    msg!(
+        "Executed hook {} with {}",
+        ctx.accounts.hook_registration.hook_id,
+        hook_params.amount
+    );
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! FundEventRentPool instruction handler
+
+use {
+    crate::events::EventRentPoolFunded,
+    anchor_lang::prelude::*,
+    anchor_lang::system_program::{transfer, Transfer},
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundEventRentPoolContext<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    /// CHECK: program-funded event_rent_pool PDA, system owned
+    #[account(
+        mut,
+        seeds = [b"event_rent_pool"],
+        bump
+    )]
+    pub event_rent_pool: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct FundEventRentPoolParams {
+    pub lamports: u64,
+}
+
+// Instruction handler
+pub fn fund_event_rent_pool(
+    ctx: Context<FundEventRentPoolContext>,
+    params: &FundEventRentPoolParams,
+) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.event_rent_pool.to_account_info(),
+            },
+        ),
+        params.lamports,
+    )?;
+
+    emit_cpi!(EventRentPoolFunded {
+        funder: ctx.accounts.funder.key(),
+        lamports: params.lamports,
+    });
+
+    Ok(())
+}
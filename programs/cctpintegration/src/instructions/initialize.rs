@@ -0,0 +1,128 @@
+//! Initialize instruction handler
+
+use {
+    crate::{error::ErrorCode, events::Initialized, program, state::BridgeConfig},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account()]
+    pub upgrade_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = BridgeConfig::DISCRIMINATOR.len() + BridgeConfig::INIT_SPACE,
+        seeds = [b"bridge_config"],
+        bump
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, owns the staging token account and signs burns on behalf of the program
+    #[account(
+        seeds = [b"integration_authority"],
+        bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    /// CHECK: empty PDA, destination_caller of relayed transfers, signs receive_message for relayers
+    #[account(
+        seeds = [b"relayer_authority"],
+        bump
+    )]
+    pub relayer_authority: UncheckedAccount<'info>,
+
+    #[account()]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Holds the net amount between the fee transfer and the burn in bridge_and_deposit_for_burn
+    #[account(
+        init,
+        payer = payer,
+        token::mint = usdc_mint,
+        token::authority = integration_authority,
+        token::token_program = token_program,
+        seeds = [
+            b"staging",
+            usdc_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub staging_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = vault_usdc.mint == usdc_mint.key() @ ErrorCode::InvalidVaultAccount
+    )]
+    pub vault_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // Ensure only upgrade_authority can call initialize
+    #[account(constraint = cctpintegration_program_data.upgrade_authority_address == Some(upgrade_authority.key()))]
+    pub cctpintegration_program_data: Account<'info, ProgramData>,
+
+    pub cctpintegration_program: Program<'info, program::Cctpintegration>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct InitializeParams {
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+}
+
+// Instruction handler
+pub fn initialize(ctx: Context<InitializeContext>, params: &InitializeParams) -> Result<()> {
+    // Validate cctpintegration_program_data
+    if let Some(programdata_address) = ctx.accounts.cctpintegration_program.programdata_address()? {
+        require_keys_eq!(
+            programdata_address,
+            ctx.accounts.cctpintegration_program_data.key(),
+            anchor_lang::error::ErrorCode::InvalidProgramExecutable
+        );
+    }
+
+    // record bridge_config state
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    bridge_config.owner = ctx.accounts.upgrade_authority.key();
+    bridge_config.fee_manager = ctx.accounts.upgrade_authority.key();
+    bridge_config.pauser = ctx.accounts.upgrade_authority.key();
+    bridge_config.domain_manager = ctx.accounts.upgrade_authority.key();
+    bridge_config.compliance = ctx.accounts.upgrade_authority.key();
+    bridge_config.usdc_mint = ctx.accounts.usdc_mint.key();
+    bridge_config.fee_bps = params.fee_bps;
+    bridge_config.min_fee_amount = params.min_fee_amount;
+    bridge_config.max_fee_amount = params.max_fee_amount;
+    bridge_config.vault_usdc = ctx.accounts.vault_usdc.key();
+    bridge_config.bump = ctx.bumps.bridge_config;
+    bridge_config.authority_bump = ctx.bumps.integration_authority;
+    bridge_config.staging_bump = ctx.bumps.staging_usdc;
+    bridge_config.relayer_authority_bump = ctx.bumps.relayer_authority;
+
+    // validate the state
+    require!(
+        bridge_config.validate(),
+        ErrorCode::InvalidBridgeConfigState
+    );
+
+    emit_cpi!(Initialized {
+        owner: bridge_config.owner,
+        usdc_mint: bridge_config.usdc_mint,
+        vault_usdc: bridge_config.vault_usdc,
+        fee_bps: params.fee_bps,
+        min_fee_amount: params.min_fee_amount,
+        max_fee_amount: params.max_fee_amount,
+    });
+
+    Ok(())
+}
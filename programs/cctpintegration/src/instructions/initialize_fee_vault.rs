@@ -0,0 +1,83 @@
+//! InitializeFeeVault instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::FeeVaultInitialized,
+        state::{BridgeConfig, FeeLedger},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeFeeVaultContext<'info> {
+    #[account(mut)]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, owns the fee vault
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account()]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Receives every integration fee and sponsor surcharge collected in the mint
+    #[account(
+        init,
+        payer = fee_manager,
+        token::mint = mint,
+        token::authority = integration_authority,
+        token::token_program = token_program,
+        seeds = [
+            b"fee_vault",
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = fee_manager,
+        space = FeeLedger::DISCRIMINATOR.len() + FeeLedger::INIT_SPACE,
+        seeds = [
+            b"fee_ledger",
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub fee_ledger: Box<Account<'info, FeeLedger>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction handler
+pub fn initialize_fee_vault(ctx: Context<InitializeFeeVaultContext>) -> Result<()> {
+    let fee_ledger = ctx.accounts.fee_ledger.as_mut();
+    fee_ledger.mint = ctx.accounts.mint.key();
+    fee_ledger.fee_vault = ctx.accounts.fee_vault.key();
+    fee_ledger.bump = ctx.bumps.fee_ledger;
+    fee_ledger.fee_vault_bump = ctx.bumps.fee_vault;
+
+    emit_cpi!(FeeVaultInitialized {
+        mint: fee_ledger.mint,
+        fee_vault: fee_ledger.fee_vault,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! InitializeInbound instruction handler
+
+use {
+    crate::{error::ErrorCode, events::InboundInitialized, state::BridgeConfig},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{Mint, Token, TokenAccount},
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeInboundContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, owns the inbound token account
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(address = bridge_config.usdc_mint @ ErrorCode::InvalidMint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    // Receives mints from CCTP before they are forwarded, used as mint_recipient by senders
+    #[account(
+        init,
+        payer = owner,
+        token::mint = usdc_mint,
+        token::authority = integration_authority,
+        seeds = [
+            b"inbound",
+            usdc_mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub inbound_usdc: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction handler
+pub fn initialize_inbound(ctx: Context<InitializeInboundContext>) -> Result<()> {
+    ctx.accounts.bridge_config.inbound_bump = ctx.bumps.inbound_usdc;
+
+    emit_cpi!(InboundInitialized {
+        mint: ctx.accounts.usdc_mint.key(),
+        inbound_usdc: ctx.accounts.inbound_usdc.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+//! MarkTransferAttested instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::TransferAttested,
+        state::{TransferRecord, TransferStatus},
+    },
+    anchor_lang::prelude::*,
+    message_transmitter_v2::{
+        events::MessageSent, instructions::ReclaimEventAccountParams, message::Message,
+        state::MessageTransmitter,
+    },
+};
+
+// Instruction accounts
+// Permissionless: the record only moves to Attested once the destination message matches the
+// source fields of message_sent_event_data and its attestation is signed by enabled attesters,
+// the same checks MessageTransmitter runs before reclaiming the event account.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MarkTransferAttestedContext<'info> {
+    pub message_transmitter: Box<Account<'info, MessageTransmitter>>,
+
+    pub message_sent_event_data: Box<Account<'info, MessageSent>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"transfer_record",
+            transfer_record.user.as_ref(),
+            &transfer_record.index.to_le_bytes()
+        ],
+        bump = transfer_record.bump,
+        constraint = transfer_record.message_sent_event_data == message_sent_event_data.key() @ ErrorCode::InvalidTransferRecord,
+        constraint = transfer_record.status == TransferStatus::Pending @ ErrorCode::InvalidTransferStatus
+    )]
+    pub transfer_record: Box<Account<'info, TransferRecord>>,
+}
+
+// Instruction handler
+pub fn mark_transfer_attested(
+    ctx: Context<MarkTransferAttestedContext>,
+    params: &ReclaimEventAccountParams,
+) -> Result<()> {
+    let message_transmitter = ctx.accounts.message_transmitter.as_ref();
+    let message = Message::new(
+        message_transmitter.version,
+        &ctx.accounts.message_sent_event_data.message,
+    )?;
+    let destination_message =
+        Message::new(message_transmitter.version, &params.destination_message)?;
+
+    require!(
+        message.hash_source_fields() == destination_message.hash_source_fields(),
+        ErrorCode::InvalidTransferRecord
+    );
+    message_transmitter
+        .verify_attestation_signatures(&destination_message.hash(), &params.attestation)?;
+
+    let transfer_record = ctx.accounts.transfer_record.as_mut();
+    transfer_record.status = TransferStatus::Attested;
+    transfer_record.attested_at = Clock::get()?.unix_timestamp;
+
+    emit_cpi!(TransferAttested {
+        user: transfer_record.user,
+        index: transfer_record.index,
+        message_sent_event_data: transfer_record.message_sent_event_data,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+//! Pause instruction handler
+
+use {
+    crate::{error::ErrorCode, events::Pause, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseContext<'info> {
+    #[account()]
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = pauser @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction handler
+pub fn pause(ctx: Context<PauseContext>) -> Result<()> {
+    require!(
+        !ctx.accounts.bridge_config.paused,
+        ErrorCode::InvalidBridgeConfigState
+    );
+
+    ctx.accounts.bridge_config.paused = true;
+
+    emit_cpi!(Pause {});
+
+    Ok(())
+}
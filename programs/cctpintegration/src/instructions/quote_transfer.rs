@@ -0,0 +1,109 @@
+//! QuoteTransfer instruction handler
+
+use {
+    crate::{
+        cctp,
+        error::ErrorCode,
+        state::{BridgeConfig, UserStats},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::Mint,
+    token_messenger_minter_v2::{
+        token_messenger_v2::state::TokenMessenger, token_minter_v2::state::LocalToken,
+    },
+};
+
+// Instruction accounts
+#[derive(Accounts)]
+pub struct QuoteTransferContext<'info> {
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        seeds = [b"token_messenger"],
+        bump,
+        seeds::program = token_messenger_minter_v2::ID
+    )]
+    pub token_messenger: Box<Account<'info, TokenMessenger>>,
+
+    #[account(
+        constraint = local_token.key() == cctp::local_token_address(&burn_token_mint.key(), local_token.bump)? @ ErrorCode::InvalidLocalToken
+    )]
+    pub local_token: Box<Account<'info, LocalToken>>,
+
+    // Only SPL Token mints can be burned, as in CctpTransfer
+    #[account(
+        address = bridge_config.usdc_mint @ ErrorCode::InvalidMint,
+        owner = anchor_spl::token::ID @ ErrorCode::UnsupportedBurnMint
+    )]
+    pub burn_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // Stats of the quoted user, None quotes the base fee tier
+    #[account(
+        seeds = [b"user_stats", user_stats.user.as_ref()],
+        bump = user_stats.bump
+    )]
+    pub user_stats: Option<Box<Account<'info, UserStats>>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct QuoteTransferParams {
+    // Gross amount taken from the user, as passed to bridge_and_deposit_for_burn
+    pub amount: u64,
+}
+
+/// Cost breakdown of a bridge_and_deposit_for_burn of the quoted amount
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
+pub struct TransferQuote {
+    pub integration_fee: u64,
+    // Volume fee tier the integration fee is priced at
+    pub fee_tier: u8,
+    // Amount burned by TokenMessengerMinter after the integration fee
+    pub burn_amount: u64,
+    // Smallest max_fee TokenMessengerMinter accepts for finalized transfers
+    pub cctp_min_fee_standard: u64,
+    // Smallest max_fee TokenMessengerMinter accepts for fast transfers
+    pub cctp_min_fee_fast: u64,
+    // Amount minted on the destination domain when the minimum standard fee is charged
+    pub net_minted_amount: u64,
+    // The burn would be refused by TokenMessengerMinter
+    pub exceeds_burn_limit: bool,
+}
+
+// Instruction handler
+// Read-only, the quote is returned through Anchor return data.
+pub fn quote_transfer(
+    ctx: Context<QuoteTransferContext>,
+    params: &QuoteTransferParams,
+) -> Result<TransferQuote> {
+    let volume = match &ctx.accounts.user_stats {
+        Some(user_stats) => user_stats.rolling_volume(Clock::get()?.unix_timestamp),
+        None => 0,
+    };
+    let fee_tier = ctx.accounts.bridge_config.get_fee_tier(volume);
+    let integration_fee = ctx
+        .accounts
+        .bridge_config
+        .get_tiered_fee_amount(params.amount, fee_tier)?;
+    let burn_amount = params.amount - integration_fee;
+
+    // TokenMessengerMinter applies the same min_fee whatever the finality threshold
+    let cctp_min_fee = ctx
+        .accounts
+        .token_messenger
+        .get_min_fee_amount(burn_amount)?;
+
+    Ok(TransferQuote {
+        integration_fee,
+        fee_tier,
+        burn_amount,
+        cctp_min_fee_standard: cctp_min_fee,
+        cctp_min_fee_fast: cctp_min_fee,
+        net_minted_amount: burn_amount.saturating_sub(cctp_min_fee),
+        exceeds_burn_limit: burn_amount > ctx.accounts.local_token.burn_limit_per_message,
+    })
+}
@@ -0,0 +1,148 @@
+//! ReceiveForHook instruction handler
+
+use {
+    crate::{
+        cctp_receive::*,
+        error::ErrorCode,
+        events::HookReceived,
+        hook_payload::{HookKind, HookPayload},
+        state::{BridgeConfig, HookRegistration, PendingHook},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::TokenAccount,
+    message_transmitter_v2::{instructions::ReceiveMessageParams, message::Message},
+    token_messenger_minter_v2::token_messenger_v2::burn_message::BurnMessage,
+};
+
+// Instruction accounts
+// The minted amount stays reserved in inbound_usdc until execute_hook runs the hook.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: ReceiveMessageParams)]
+pub struct ReceiveForHookContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, signs receive_message as the caller and owns inbound_usdc
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"inbound",
+            bridge_config.usdc_mint.as_ref()
+        ],
+        bump = bridge_config.inbound_bump
+    )]
+    pub inbound_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// Each received hook is stored in a separate PDA keyed by the message nonce
+    #[account(
+        init,
+        payer = payer,
+        space = PendingHook::space_for_message(&params.message)?,
+        seeds = [
+            b"pending_hook",
+            &params.message[Message::NONCE_INDEX..Message::SENDER_INDEX]
+        ],
+        bump
+    )]
+    pub pending_hook: Box<Account<'info, PendingHook>>,
+
+    /// Registration of the hook type id carried by the message, checked in the handler
+    #[account(
+        seeds = [b"hook_registration".as_ref(), &[hook_registration.hook_id]],
+        bump = hook_registration.bump
+    )]
+    pub hook_registration: Box<Account<'info, HookRegistration>>,
+
+    pub cctp: CctpReceive<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction handler
+pub fn receive_for_hook(
+    ctx: Context<ReceiveForHookContext>,
+    params: &ReceiveMessageParams,
+) -> Result<()> {
+    let message = Message::new(
+        ctx.accounts.cctp.message_transmitter.version,
+        &params.message,
+    )?;
+    let burn_message = BurnMessage::new(
+        ctx.accounts.cctp.token_messenger.message_body_version,
+        message.message_body(),
+    )?;
+
+    require_keys_eq!(
+        burn_message.mint_recipient()?,
+        ctx.accounts.inbound_usdc.key(),
+        ErrorCode::InvalidRecipient
+    );
+
+    let hook_payload = HookPayload::new(burn_message.hook_data())?;
+    let hook_kind = hook_payload.kind();
+    require!(
+        hook_kind != HookKind::Forward,
+        ErrorCode::InvalidHookPayload
+    );
+    require_eq!(
+        ctx.accounts.hook_registration.hook_id,
+        hook_kind.id(),
+        ErrorCode::InvalidHookPayload
+    );
+
+    let balance_before = ctx.accounts.inbound_usdc.amount;
+
+    let authority_seeds: &[&[&[u8]]] = &[&[
+        b"integration_authority",
+        &[ctx.accounts.bridge_config.authority_bump],
+    ]];
+
+    ctx.accounts.cctp.receive_message(
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.integration_authority.to_account_info(),
+        ctx.accounts.inbound_usdc.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        authority_seeds,
+        params,
+    )?;
+
+    ctx.accounts.inbound_usdc.reload()?;
+
+    // Keep the minted amount in inbound_usdc until execute_hook runs the registered program
+    let pending_hook = ctx.accounts.pending_hook.as_mut();
+    pending_hook.rent_payer = ctx.accounts.payer.key();
+    pending_hook.hook_id = hook_kind.id();
+    pending_hook.amount = ctx.accounts.inbound_usdc.amount - balance_before;
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    bridge_config.inbound_reserved = bridge_config
+        .inbound_reserved
+        .checked_add(pending_hook.amount)
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+    pending_hook.source_domain = message.source_domain()?;
+    pending_hook.nonce = message.nonce()?;
+    pending_hook.hook_payload = burn_message.hook_data().to_vec();
+
+    emit_cpi!(HookReceived {
+        hook_id: pending_hook.hook_id,
+        source_domain: pending_hook.source_domain,
+        nonce: pending_hook.nonce,
+        amount: pending_hook.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! ReclaimEventAccount instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::EventAccountReclaimed,
+        state::{TransferRecord, TransferStatus, UserTransferCounter},
+    },
+    anchor_lang::prelude::*,
+    message_transmitter_v2::{
+        cpi::{
+            accounts::ReclaimEventAccountContext as CctpReclaimEventAccountContext,
+            reclaim_event_account as cctp_reclaim_event_account,
+        },
+        events::MessageSent,
+        instructions::ReclaimEventAccountParams,
+        program::MessageTransmitterV2,
+        state::MessageTransmitter,
+    },
+};
+
+// Instruction accounts
+// Returns the rent of a MessageSent account to its rent payer: permissionless for accounts paid
+// by event_rent_pool, signed by the payer otherwise. MessageTransmitter verifies the attestation
+// of the message, so the crank also marks the transfer record of the event account as reclaimed,
+// whether or not mark_transfer_attested ran before.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimEventAccountContext<'info> {
+    /// CHECK: rent payer of message_sent_event_data, event_rent_pool or the deposit payer
+    #[account(
+        mut,
+        address = message_sent_event_data.rent_payer @ ErrorCode::InvalidAuthority
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub message_transmitter: Box<Account<'info, MessageTransmitter>>,
+
+    #[account(
+        mut,
+        constraint = Clock::get()?.unix_timestamp >= message_sent_event_data.created_at + MessageTransmitter::EVENT_ACCOUNT_WINDOW_SECONDS @ ErrorCode::EventAccountWindowNotExpired
+    )]
+    pub message_sent_event_data: Box<Account<'info, MessageSent>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"transfer_record",
+            transfer_record.user.as_ref(),
+            &transfer_record.index.to_le_bytes()
+        ],
+        bump = transfer_record.bump,
+        constraint = transfer_record.message_sent_event_data == message_sent_event_data.key() @ ErrorCode::InvalidTransferRecord,
+        constraint = transfer_record.status != TransferStatus::Reclaimed @ ErrorCode::InvalidTransferStatus
+    )]
+    pub transfer_record: Box<Account<'info, TransferRecord>>,
+
+    #[account(
+        mut,
+        seeds = [b"user_transfer_counter", transfer_record.user.as_ref()],
+        bump = user_transfer_counter.bump
+    )]
+    pub user_transfer_counter: Box<Account<'info, UserTransferCounter>>,
+
+    pub message_transmitter_program: Program<'info, MessageTransmitterV2>,
+}
+
+// Instruction handler
+pub fn reclaim_event_account(
+    ctx: Context<ReclaimEventAccountContext>,
+    params: &ReclaimEventAccountParams,
+) -> Result<()> {
+    let (event_rent_pool, event_rent_bump) =
+        Pubkey::find_program_address(&[b"event_rent_pool"], &crate::ID);
+    let pool_paid = ctx.accounts.rent_payer.key() == event_rent_pool;
+    require!(
+        pool_paid || ctx.accounts.rent_payer.is_signer,
+        ErrorCode::InvalidAuthority
+    );
+
+    let cpi_accounts = CctpReclaimEventAccountContext {
+        payee: ctx.accounts.rent_payer.to_account_info(),
+        message_transmitter: ctx.accounts.message_transmitter.to_account_info(),
+        message_sent_event_data: ctx.accounts.message_sent_event_data.to_account_info(),
+    };
+
+    let event_rent_seeds: &[&[&[u8]]] = &[&[b"event_rent_pool", &[event_rent_bump]]];
+    let signer_seeds: &[&[&[u8]]] = if pool_paid { event_rent_seeds } else { &[] };
+
+    let cpi_program = ctx.accounts.message_transmitter_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+    cctp_reclaim_event_account(cpi_ctx, params.clone())?;
+
+    if pool_paid {
+        ctx.accounts.user_transfer_counter.end_subsidy();
+    }
+
+    let transfer_record = ctx.accounts.transfer_record.as_mut();
+    transfer_record.status = TransferStatus::Reclaimed;
+    transfer_record.reclaimed_at = Clock::get()?.unix_timestamp;
+
+    emit_cpi!(EventAccountReclaimed {
+        rent_payer: ctx.accounts.rent_payer.key(),
+        message_sent_event_data: transfer_record.message_sent_event_data,
+        user: transfer_record.user,
+        index: transfer_record.index,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+//! RecoverInbound instruction handler
+
+use {
+    crate::{error::ErrorCode, events::InboundRecovered, state::BridgeConfig},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{transfer, Token, TokenAccount, Transfer},
+};
+
+// Instruction accounts
+// Moves USDC minted into inbound_usdc without being forwarded, e.g. by messages received
+// directly through MessageTransmitter. Amounts reserved for pending hooks cannot be recovered.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RecoverInboundContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, owns inbound_usdc
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"inbound",
+            bridge_config.usdc_mint.as_ref()
+        ],
+        bump = bridge_config.inbound_bump
+    )]
+    pub inbound_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == bridge_config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct RecoverInboundParams {
+    pub amount: u64,
+}
+
+// Instruction handler
+pub fn recover_inbound(
+    ctx: Context<RecoverInboundContext>,
+    params: &RecoverInboundParams,
+) -> Result<()> {
+    require_gt!(params.amount, 0, ErrorCode::ZeroAmount);
+    require_gte!(
+        ctx.accounts
+            .bridge_config
+            .recoverable_inbound_amount(ctx.accounts.inbound_usdc.amount),
+        params.amount,
+        ErrorCode::InboundAmountReserved
+    );
+
+    let authority_seeds: &[&[&[u8]]] = &[&[
+        b"integration_authority",
+        &[ctx.accounts.bridge_config.authority_bump],
+    ]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.inbound_usdc.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.integration_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        params.amount,
+    )?;
+
+    emit_cpi!(InboundRecovered {
+        mint: ctx.accounts.inbound_usdc.mint,
+        destination: ctx.accounts.destination.key(),
+        amount: params.amount,
+    });
+
+    Ok(())
+}
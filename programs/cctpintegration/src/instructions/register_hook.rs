@@ -0,0 +1,90 @@
+//! RegisterHook instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::HookRegistered,
+        hook_payload::HookKind,
+        state::{BridgeConfig, HookAccountMeta, HookRegistration},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: RegisterHookParams)]
+pub struct RegisterHookContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = HookRegistration::DISCRIMINATOR.len() + HookRegistration::INIT_SPACE,
+        seeds = [b"hook_registration".as_ref(), &[params.hook_id]],
+        bump
+    )]
+    pub hook_registration: Box<Account<'info, HookRegistration>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterHookParams {
+    pub hook_id: u8,
+    pub target_program: Pubkey,
+    pub token_account: Pubkey,
+    pub accounts: Vec<HookAccountMeta>,
+}
+
+// Instruction handler
+pub fn register_hook(ctx: Context<RegisterHookContext>, params: &RegisterHookParams) -> Result<()> {
+    // Forward hooks are executed by receive_and_forward and never go through the registry
+    require_neq!(
+        params.hook_id,
+        HookKind::FORWARD_ID,
+        ErrorCode::InvalidHookPayload
+    );
+
+    // The registered program must not be able to call back into CCTP or into this program
+    require!(
+        ![
+            Pubkey::default(),
+            crate::ID,
+            message_transmitter_v2::ID,
+            token_messenger_minter_v2::ID,
+        ]
+        .contains(&params.target_program),
+        ErrorCode::InvalidHookProgram
+    );
+
+    require_gte!(
+        HookRegistration::MAX_ACCOUNTS,
+        params.accounts.len(),
+        ErrorCode::InvalidHookAccounts
+    );
+
+    let hook_registration = ctx.accounts.hook_registration.as_mut();
+    hook_registration.hook_id = params.hook_id;
+    hook_registration.target_program = params.target_program;
+    hook_registration.token_account = params.token_account;
+    hook_registration.accounts = params.accounts.clone();
+    hook_registration.bump = ctx.bumps.hook_registration;
+
+    emit_cpi!(HookRegistered {
+        hook_id: params.hook_id,
+        target_program: params.target_program,
+        token_account: params.token_account,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+//! RelayAndForward instruction handler
+
+use {
+    crate::{
+        cctp_receive::*,
+        error::ErrorCode,
+        events::CctpTransferCompleted,
+        hook_payload::{HookKind, HookPayload},
+        state::{BridgeConfig, Relayer},
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::{transfer, TokenAccount, Transfer},
+    message_transmitter_v2::{instructions::ReceiveMessageParams, message::Message},
+    token_messenger_minter_v2::token_messenger_v2::burn_message::BurnMessage,
+};
+
+// Instruction accounts
+// Messages sent with deposit_for_burn_relayed_cctp name relayer_authority as destination_caller,
+// so only registered relayers can complete them through this instruction.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RelayAndForwardContext<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [b"relayer", relayer.key().as_ref()],
+        bump = relayer_registration.bump
+    )]
+    pub relayer_registration: Box<Account<'info, Relayer>>,
+
+    #[account(
+        mut,
+        token::mint = bridge_config.usdc_mint,
+        token::authority = relayer
+    )]
+    pub relayer_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        constraint = !bridge_config.paused @ ErrorCode::ProgramPaused
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, signs receive_message as the caller of relayed messages
+    #[account(
+        seeds = [b"relayer_authority"],
+        bump = bridge_config.relayer_authority_bump
+    )]
+    pub relayer_authority: UncheckedAccount<'info>,
+
+    /// CHECK: empty PDA, owns inbound_usdc
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"inbound",
+            bridge_config.usdc_mint.as_ref()
+        ],
+        bump = bridge_config.inbound_bump
+    )]
+    pub inbound_usdc: Box<Account<'info, TokenAccount>>,
+
+    // Final recipient named by the hook payload
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == bridge_config.usdc_mint @ ErrorCode::InvalidMint
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: BlockedAddress PDA of the recipient, must not exist
+    #[account(
+        seeds = [b"blocked_address", recipient_token_account.owner.as_ref()],
+        bump,
+        constraint = blocked_recipient.data_is_empty() @ ErrorCode::AddressBlocked
+    )]
+    pub blocked_recipient: UncheckedAccount<'info>,
+
+    pub cctp: CctpReceive<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction handler
+pub fn relay_and_forward(
+    ctx: Context<RelayAndForwardContext>,
+    params: &ReceiveMessageParams,
+) -> Result<()> {
+    let message = Message::new(
+        ctx.accounts.cctp.message_transmitter.version,
+        &params.message,
+    )?;
+    let burn_message = BurnMessage::new(
+        ctx.accounts.cctp.token_messenger.message_body_version,
+        message.message_body(),
+    )?;
+
+    require_keys_eq!(
+        burn_message.mint_recipient()?,
+        ctx.accounts.inbound_usdc.key(),
+        ErrorCode::InvalidRecipient
+    );
+
+    let hook_payload = HookPayload::new(burn_message.hook_data())?;
+    require!(
+        hook_payload.kind() == HookKind::Forward,
+        ErrorCode::InvalidHookPayload
+    );
+    require_keys_eq!(
+        ctx.accounts.recipient_token_account.owner,
+        hook_payload.target()?,
+        ErrorCode::InvalidRecipient
+    );
+    let relayer_fee = hook_payload.relayer_fee()?;
+
+    let balance_before = ctx.accounts.inbound_usdc.amount;
+
+    ctx.accounts.cctp.receive_message(
+        ctx.accounts.relayer.to_account_info(),
+        ctx.accounts.relayer_authority.to_account_info(),
+        ctx.accounts.inbound_usdc.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        &[&[
+            b"relayer_authority",
+            &[ctx.accounts.bridge_config.relayer_authority_bump],
+        ]],
+        params,
+    )?;
+
+    ctx.accounts.inbound_usdc.reload()?;
+    let minted_amount = ctx.accounts.inbound_usdc.amount - balance_before;
+    require_gt!(minted_amount, relayer_fee, ErrorCode::InvalidFee);
+    let forward_amount = minted_amount - relayer_fee;
+
+    let authority_seeds: &[&[&[u8]]] = &[&[
+        b"integration_authority",
+        &[ctx.accounts.bridge_config.authority_bump],
+    ]];
+
+    if relayer_fee > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.cctp.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.inbound_usdc.to_account_info(),
+                    to: ctx.accounts.relayer_usdc.to_account_info(),
+                    authority: ctx.accounts.integration_authority.to_account_info(),
+                },
+                authority_seeds,
+            ),
+            relayer_fee,
+        )?;
+    }
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.cctp.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.inbound_usdc.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.integration_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        forward_amount,
+    )?;
+
+    emit_cpi!(CctpTransferCompleted {
+        source_domain: message.source_domain()?,
+        nonce: message.nonce()?,
+        mint_token: ctx.accounts.inbound_usdc.mint,
+        recipient_token_account: ctx.accounts.recipient_token_account.key(),
+        amount: forward_amount,
+        relayer_fee,
+    });
+
+    msg!(
+        "Relayed {} via CCTP to {}, relayer fee {}",
+        forward_amount,
+        ctx.accounts.recipient_token_account.key(),
+        relayer_fee
+    );
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! RemoveRelayer instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::RelayerRemoved,
+        state::{BridgeConfig, Relayer},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveRelayerContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"relayer", relayer.relayer.as_ref()],
+        bump = relayer.bump,
+        close = owner
+    )]
+    pub relayer: Box<Account<'info, Relayer>>,
+}
+
+// Instruction handler
+pub fn remove_relayer(ctx: Context<RemoveRelayerContext>) -> Result<()> {
+    emit_cpi!(RelayerRemoved {
+        relayer: ctx.accounts.relayer.relayer,
+    });
+
+    Ok(())
+}
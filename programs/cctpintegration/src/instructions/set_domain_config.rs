@@ -0,0 +1,89 @@
+//! SetDomainConfig instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::DomainConfigUpdated,
+        state::{BridgeConfig, DomainConfig, DomainType},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SetDomainConfigParams)]
+pub struct SetDomainConfigContext<'info> {
+    #[account(mut)]
+    pub domain_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = domain_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = domain_manager,
+        space = DomainConfig::DISCRIMINATOR.len() + DomainConfig::INIT_SPACE,
+        seeds = [b"domain_config".as_ref(), &params.domain.to_le_bytes()],
+        bump
+    )]
+    pub domain_config: Box<Account<'info, DomainConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetDomainConfigParams {
+    pub domain: u32,
+    pub domain_type: DomainType,
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub default_min_finality_threshold: u32,
+    pub default_max_fee: u64,
+    pub max_fee_buffer: u64,
+    pub max_fast_amount: u64,
+    // Inbound token account of cctpintegration on the domain, default if it is not deployed there
+    pub inbound_usdc: Pubkey,
+}
+
+// Instruction handler
+pub fn set_domain_config(
+    ctx: Context<SetDomainConfigContext>,
+    params: &SetDomainConfigParams,
+) -> Result<()> {
+    let domain_config = ctx.accounts.domain_config.as_mut();
+    domain_config.domain = params.domain;
+    domain_config.domain_type = params.domain_type;
+    domain_config.enabled = params.enabled;
+    domain_config.min_amount = params.min_amount;
+    domain_config.max_amount = params.max_amount;
+    domain_config.default_min_finality_threshold = params.default_min_finality_threshold;
+    domain_config.default_max_fee = params.default_max_fee;
+    domain_config.max_fee_buffer = params.max_fee_buffer;
+    domain_config.max_fast_amount = params.max_fast_amount;
+    domain_config.inbound_usdc = params.inbound_usdc;
+    domain_config.bump = ctx.bumps.domain_config;
+
+    require!(domain_config.validate(), ErrorCode::InvalidDomainConfig);
+
+    emit_cpi!(DomainConfigUpdated {
+        domain: params.domain,
+        domain_type: params.domain_type,
+        enabled: params.enabled,
+        min_amount: params.min_amount,
+        max_amount: params.max_amount,
+        default_min_finality_threshold: params.default_min_finality_threshold,
+        default_max_fee: params.default_max_fee,
+        max_fee_buffer: params.max_fee_buffer,
+        max_fast_amount: params.max_fast_amount,
+        inbound_usdc: params.inbound_usdc,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! SetFee instruction handler
+
+use {
+    crate::{error::ErrorCode, events::FeeUpdated, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFeeContext<'info> {
+    #[account()]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetFeeParams {
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+}
+
+// Instruction handler
+pub fn set_fee(ctx: Context<SetFeeContext>, params: &SetFeeParams) -> Result<()> {
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    bridge_config.fee_bps = params.fee_bps;
+    bridge_config.min_fee_amount = params.min_fee_amount;
+    bridge_config.max_fee_amount = params.max_fee_amount;
+
+    require!(
+        bridge_config.validate(),
+        ErrorCode::InvalidBridgeConfigState
+    );
+
+    emit_cpi!(FeeUpdated {
+        fee_bps: params.fee_bps,
+        min_fee_amount: params.min_fee_amount,
+        max_fee_amount: params.max_fee_amount,
+    });
+
+    Ok(())
+}
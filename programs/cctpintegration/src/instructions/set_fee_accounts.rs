@@ -0,0 +1,42 @@
+//! SetFeeAccounts instruction handler
+
+use {
+    crate::{error::ErrorCode, events::VaultUpdated, state::BridgeConfig},
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::TokenAccount,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFeeAccountsContext<'info> {
+    #[account()]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        constraint = vault_usdc.mint == bridge_config.usdc_mint @ ErrorCode::InvalidVaultAccount
+    )]
+    pub vault_usdc: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+// Instruction handler
+pub fn set_fee_accounts(ctx: Context<SetFeeAccountsContext>) -> Result<()> {
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    let previous_vault = bridge_config.vault_usdc;
+    bridge_config.vault_usdc = ctx.accounts.vault_usdc.key();
+
+    emit_cpi!(VaultUpdated {
+        previous_vault,
+        new_vault: bridge_config.vault_usdc,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! SetFeeTiers instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::FeeTiersUpdated,
+        state::{BridgeConfig, FeeTier},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetFeeTiersContext<'info> {
+    #[account()]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetFeeTiersParams {
+    // Ordered by min_volume, an empty table charges fee_bps to every user
+    pub fee_tiers: Vec<FeeTier>,
+}
+
+// Instruction handler
+pub fn set_fee_tiers(ctx: Context<SetFeeTiersContext>, params: &SetFeeTiersParams) -> Result<()> {
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    bridge_config.fee_tiers = params.fee_tiers.clone();
+
+    require!(
+        bridge_config.validate(),
+        ErrorCode::InvalidBridgeConfigState
+    );
+
+    emit_cpi!(FeeTiersUpdated {
+        fee_tiers: params.fee_tiers.clone(),
+    });
+
+    Ok(())
+}
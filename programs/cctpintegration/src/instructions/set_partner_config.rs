@@ -0,0 +1,67 @@
+//! SetPartnerConfig instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::PartnerConfigUpdated,
+        state::{BridgeConfig, PartnerConfig, PartnerShare},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SetPartnerConfigParams)]
+pub struct SetPartnerConfigContext<'info> {
+    #[account(mut)]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_manager,
+        space = PartnerConfig::DISCRIMINATOR.len() + PartnerConfig::INIT_SPACE,
+        seeds = [b"partner_config".as_ref(), &params.partner_id.to_le_bytes()],
+        bump
+    )]
+    pub partner_config: Box<Account<'info, PartnerConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetPartnerConfigParams {
+    pub partner_id: u16,
+    pub enabled: bool,
+    pub shares: Vec<PartnerShare>,
+}
+
+// Instruction handler
+pub fn set_partner_config(
+    ctx: Context<SetPartnerConfigContext>,
+    params: &SetPartnerConfigParams,
+) -> Result<()> {
+    let partner_config = ctx.accounts.partner_config.as_mut();
+    partner_config.partner_id = params.partner_id;
+    partner_config.enabled = params.enabled;
+    partner_config.shares = params.shares.clone();
+    partner_config.bump = ctx.bumps.partner_config;
+
+    require!(partner_config.validate(), ErrorCode::InvalidPartnerConfig);
+
+    emit_cpi!(PartnerConfigUpdated {
+        partner_id: params.partner_id,
+        enabled: params.enabled,
+        shares: params.shares.clone(),
+    });
+
+    Ok(())
+}
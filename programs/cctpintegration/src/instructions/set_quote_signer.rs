@@ -0,0 +1,46 @@
+//! SetQuoteSigner instruction handler
+
+use {
+    crate::{error::ErrorCode, events::QuoteSignerUpdated, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetQuoteSignerContext<'info> {
+    #[account()]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetQuoteSignerParams {
+    // Pubkey::default() disables quoted bridges and deposits
+    pub quote_signer: Pubkey,
+}
+
+// Instruction handler
+pub fn set_quote_signer(
+    ctx: Context<SetQuoteSignerContext>,
+    params: &SetQuoteSignerParams,
+) -> Result<()> {
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+    let previous_quote_signer = bridge_config.quote_signer;
+    bridge_config.quote_signer = params.quote_signer;
+
+    emit_cpi!(QuoteSignerUpdated {
+        previous_quote_signer,
+        new_quote_signer: params.quote_signer,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! SetSponsorConfig instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::SponsorConfigUpdated,
+        state::{BridgeConfig, SponsorConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSponsorConfigContext<'info> {
+    #[account(mut)]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_manager,
+        space = SponsorConfig::DISCRIMINATOR.len() + SponsorConfig::INIT_SPACE,
+        seeds = [b"sponsor_config"],
+        bump
+    )]
+    pub sponsor_config: Box<Account<'info, SponsorConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetSponsorConfigParams {
+    pub enabled: bool,
+    pub fee_payer: Pubkey,
+    pub usdc_per_sol: u64,
+    pub tx_fee_lamports: u64,
+}
+
+// Instruction handler
+pub fn set_sponsor_config(
+    ctx: Context<SetSponsorConfigContext>,
+    params: &SetSponsorConfigParams,
+) -> Result<()> {
+    let sponsor_config = ctx.accounts.sponsor_config.as_mut();
+    sponsor_config.enabled = params.enabled;
+    sponsor_config.fee_payer = params.fee_payer;
+    sponsor_config.usdc_per_sol = params.usdc_per_sol;
+    sponsor_config.tx_fee_lamports = params.tx_fee_lamports;
+    sponsor_config.bump = ctx.bumps.sponsor_config;
+
+    require!(sponsor_config.validate(), ErrorCode::InvalidSponsorConfig);
+
+    emit_cpi!(SponsorConfigUpdated {
+        enabled: params.enabled,
+        fee_payer: params.fee_payer,
+        usdc_per_sol: params.usdc_per_sol,
+        tx_fee_lamports: params.tx_fee_lamports,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//! SetSwapConfig instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::SwapConfigUpdated,
+        state::{BridgeConfig, SwapConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSwapConfigContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = SwapConfig::DISCRIMINATOR.len() + SwapConfig::INIT_SPACE,
+        seeds = [b"swap_config"],
+        bump
+    )]
+    pub swap_config: Box<Account<'info, SwapConfig>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SetSwapConfigParams {
+    pub enabled: bool,
+    pub swap_program: Pubkey,
+}
+
+// Instruction handler
+pub fn set_swap_config(
+    ctx: Context<SetSwapConfigContext>,
+    params: &SetSwapConfigParams,
+) -> Result<()> {
+    let swap_config = ctx.accounts.swap_config.as_mut();
+    swap_config.enabled = params.enabled;
+    swap_config.swap_program = params.swap_program;
+    swap_config.bump = ctx.bumps.swap_config;
+
+    // The swap program is called with the user signature, it must not be able to
+    // call back into CCTP or into this program
+    require!(swap_config.validate(), ErrorCode::InvalidSwapProgram);
+
+    emit_cpi!(SwapConfigUpdated {
+        enabled: params.enabled,
+        swap_program: params.swap_program,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! SwapAndDepositForBurnCctp instruction handler
+
+use {
+    crate::{
+        error::ErrorCode, events::SwapExecuted, instructions::deposit_for_burn_cctp::*,
+        state::SwapConfig, swap_adapter::SwapAdapterParams,
+    },
+    anchor_lang::prelude::*,
+    anchor_lang::solana_program::program,
+};
+
+/////////////////////////////////////////////////////////////////////////////
+/// IMPORTANT!
+/// This instruction CPIs into the configured swap program with the user
+/// signature and the caller supplied remaining accounts. No PDA of this
+/// program signs the swap CPI. Only the USDC actually received in
+/// cctp.user_usdc is burned, and the swap is reverted when it is below
+/// the user minimum.
+/// /////////////////////////////////////////////////////////////////////////
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SwapAndDepositForBurnCctpContext<'info> {
+    #[account(
+        seeds = [b"swap_config"],
+        bump = swap_config.bump,
+        constraint = swap_config.enabled @ ErrorCode::InvalidSwapProgram
+    )]
+    pub swap_config: Box<Account<'info, SwapConfig>>,
+
+    /// CHECK: configured swap program
+    #[account(
+        executable,
+        address = swap_config.swap_program @ ErrorCode::InvalidSwapProgram
+    )]
+    pub swap_program: UncheckedAccount<'info>,
+
+    pub cctp: CctpTransfer<'info>,
+    // remaining accounts: accounts of the swap program swap instruction, in order.
+    // The swap output must be delivered to cctp.user_usdc.
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SwapAndDepositForBurnCctpParams {
+    // Smallest USDC amount the swap may deliver, the whole output is burned
+    pub min_usdc_out: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    // For no destination caller, use Pubkey::default()
+    pub destination_caller: Pubkey,
+    // Unset max_fee and min_finality_threshold use the defaults of the destination domain
+    pub max_fee: Option<u64>,
+    pub min_finality_threshold: Option<u32>,
+    // Mint swapped into USDC, SOL is swapped as wrapped SOL
+    pub input_mint: Pubkey,
+    pub amount_in: u64,
+}
+
+// Instruction handler
+pub fn swap_and_deposit_for_burn_cctp<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SwapAndDepositForBurnCctpContext<'info>>,
+    params: &SwapAndDepositForBurnCctpParams,
+) -> Result<()> {
+    require_gt!(params.amount_in, 0, ErrorCode::ZeroAmount);
+    require_gt!(params.min_usdc_out, 0, ErrorCode::ZeroAmount);
+    require_keys_neq!(
+        params.input_mint,
+        ctx.accounts.cctp.burn_token_mint.key(),
+        ErrorCode::InvalidMint
+    );
+
+    let swap_params = SwapAdapterParams {
+        input_mint: params.input_mint,
+        amount_in: params.amount_in,
+        min_amount_out: params.min_usdc_out,
+    };
+    let instruction =
+        swap_params.instruction(&ctx.accounts.swap_program.key(), ctx.remaining_accounts)?;
+
+    let mut account_infos = Vec::with_capacity(1 + ctx.remaining_accounts.len());
+    account_infos.push(ctx.accounts.swap_program.to_account_info());
+    account_infos.extend(ctx.remaining_accounts.iter().cloned());
+
+    let balance_before = ctx.accounts.cctp.user_usdc.amount;
+
+    program::invoke(&instruction, &account_infos)?;
+
+    // Burn what the swap actually delivered, whatever the swap program reported
+    ctx.accounts.cctp.user_usdc.reload()?;
+    let usdc_out = ctx
+        .accounts
+        .cctp
+        .user_usdc
+        .amount
+        .saturating_sub(balance_before);
+    require_gte!(usdc_out, params.min_usdc_out, ErrorCode::SwapOutputTooLow);
+
+    let cpi_params = ctx.accounts.cctp.burn(
+        &ctx.bumps.cctp,
+        &DepositForBurnCctpParams {
+            amount: usdc_out,
+            destination_domain: params.destination_domain,
+            mint_recipient: params.mint_recipient,
+            destination_caller: params.destination_caller,
+            max_fee: params.max_fee,
+            min_finality_threshold: params.min_finality_threshold,
+        },
+        EventRentPayer::Payer,
+    )?;
+
+    emit_cpi!(SwapExecuted {
+        user: ctx.accounts.cctp.user.key(),
+        swap_program: ctx.accounts.swap_program.key(),
+        input_mint: params.input_mint,
+        amount_in: params.amount_in,
+        usdc_out,
+    });
+    emit_cpi!(ctx.accounts.cctp.deposit_initiated(&cpi_params, 0));
+
+    msg!(
+        "Swapped {} of {} into {} USDC and deposited for burn",
+        params.amount_in,
+        params.input_mint,
+        usdc_out
+    );
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//! TransferOwnership instruction handler
+
+use {
+    crate::{error::ErrorCode, events::OwnershipTransferStarted, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferOwnershipContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct TransferOwnershipParams {
+    pub new_owner: Pubkey,
+}
+
+// Instruction handler
+pub fn transfer_ownership(
+    ctx: Context<TransferOwnershipContext>,
+    params: &TransferOwnershipParams,
+) -> Result<()> {
+    if [
+        Pubkey::default(),
+        ctx.accounts.owner.key(),
+        ctx.accounts.bridge_config.pending_owner,
+    ]
+    .contains(&params.new_owner)
+    {
+        return err!(ErrorCode::InvalidOwner);
+    }
+
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+
+    bridge_config.pending_owner = params.new_owner;
+
+    emit_cpi!(OwnershipTransferStarted {
+        previous_owner: bridge_config.owner,
+        new_owner: bridge_config.pending_owner
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! UnblockAddress instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::AddressUnblocked,
+        state::{BlockedAddress, BridgeConfig},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnblockAddressContext<'info> {
+    #[account(mut)]
+    pub compliance: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = compliance @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"blocked_address", blocked_address.address.as_ref()],
+        bump = blocked_address.bump,
+        close = compliance
+    )]
+    pub blocked_address: Box<Account<'info, BlockedAddress>>,
+}
+
+// Instruction handler
+pub fn unblock_address(ctx: Context<UnblockAddressContext>) -> Result<()> {
+    emit_cpi!(AddressUnblocked {
+        address: ctx.accounts.blocked_address.address,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! UnregisterHook instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::HookUnregistered,
+        state::{BridgeConfig, HookRegistration},
+    },
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnregisterHookContext<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    #[account(
+        mut,
+        seeds = [b"hook_registration".as_ref(), &[hook_registration.hook_id]],
+        bump = hook_registration.bump,
+        close = owner
+    )]
+    pub hook_registration: Box<Account<'info, HookRegistration>>,
+}
+
+// Instruction handler
+pub fn unregister_hook(ctx: Context<UnregisterHookContext>) -> Result<()> {
+    emit_cpi!(HookUnregistered {
+        hook_id: ctx.accounts.hook_registration.hook_id,
+    });
+
+    Ok(())
+}
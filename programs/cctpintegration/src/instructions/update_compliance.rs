@@ -0,0 +1,52 @@
+//! UpdateCompliance instruction handler
+
+use {
+    crate::{error::ErrorCode, events::ComplianceUpdated, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateComplianceContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct UpdateComplianceParams {
+    pub new_compliance: Pubkey,
+}
+
+// Instruction handler
+pub fn update_compliance(
+    ctx: Context<UpdateComplianceContext>,
+    params: &UpdateComplianceParams,
+) -> Result<()> {
+    require_keys_neq!(
+        params.new_compliance,
+        Pubkey::default(),
+        ErrorCode::InvalidCompliance
+    );
+
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+
+    let previous_compliance = bridge_config.compliance;
+    bridge_config.compliance = params.new_compliance;
+
+    emit_cpi!(ComplianceUpdated {
+        previous_compliance,
+        new_compliance: bridge_config.compliance
+    });
+
+    Ok(())
+}
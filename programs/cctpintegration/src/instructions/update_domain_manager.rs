@@ -0,0 +1,52 @@
+//! UpdateDomainManager instruction handler
+
+use {
+    crate::{error::ErrorCode, events::DomainManagerUpdated, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateDomainManagerContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct UpdateDomainManagerParams {
+    pub new_domain_manager: Pubkey,
+}
+
+// Instruction handler
+pub fn update_domain_manager(
+    ctx: Context<UpdateDomainManagerContext>,
+    params: &UpdateDomainManagerParams,
+) -> Result<()> {
+    require_keys_neq!(
+        params.new_domain_manager,
+        Pubkey::default(),
+        ErrorCode::InvalidDomainManager
+    );
+
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+
+    let previous_domain_manager = bridge_config.domain_manager;
+    bridge_config.domain_manager = params.new_domain_manager;
+
+    emit_cpi!(DomainManagerUpdated {
+        previous_domain_manager,
+        new_domain_manager: bridge_config.domain_manager
+    });
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! UpdateFeeManager instruction handler
+
+use {
+    crate::{error::ErrorCode, events::FeeManagerUpdated, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateFeeManagerContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct UpdateFeeManagerParams {
+    pub new_fee_manager: Pubkey,
+}
+
+// Instruction handler
+pub fn update_fee_manager(
+    ctx: Context<UpdateFeeManagerContext>,
+    params: &UpdateFeeManagerParams,
+) -> Result<()> {
+    require_keys_neq!(
+        params.new_fee_manager,
+        Pubkey::default(),
+        ErrorCode::InvalidFeeManager
+    );
+
+    let bridge_config = ctx.accounts.bridge_config.as_mut();
+
+    let previous_fee_manager = bridge_config.fee_manager;
+    bridge_config.fee_manager = params.new_fee_manager;
+
+    emit_cpi!(FeeManagerUpdated {
+        previous_fee_manager,
+        new_fee_manager: bridge_config.fee_manager
+    });
+
+    Ok(())
+}
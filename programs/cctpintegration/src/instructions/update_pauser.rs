@@ -0,0 +1,50 @@
+//! UpdatePauser instruction handler
+
+use {
+    crate::{error::ErrorCode, events::PauserChanged, state::BridgeConfig},
+    anchor_lang::prelude::*,
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePauserContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct UpdatePauserParams {
+    pub new_pauser: Pubkey,
+}
+
+// Instruction handler
+pub fn update_pauser(ctx: Context<UpdatePauserContext>, params: &UpdatePauserParams) -> Result<()> {
+    require_keys_neq!(
+        params.new_pauser,
+        Pubkey::default(),
+        ErrorCode::InvalidPauser
+    );
+    require_keys_neq!(
+        params.new_pauser,
+        ctx.accounts.bridge_config.pauser,
+        ErrorCode::InvalidPauser
+    );
+
+    ctx.accounts.bridge_config.pauser = params.new_pauser;
+
+    emit_cpi!(PauserChanged {
+        new_address: ctx.accounts.bridge_config.pauser
+    });
+
+    Ok(())
+}
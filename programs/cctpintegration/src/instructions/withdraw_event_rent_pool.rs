@@ -0,0 +1,69 @@
+//! WithdrawEventRentPool instruction handler
+
+use {
+    crate::{error::ErrorCode, events::EventRentPoolWithdrawn, state::BridgeConfig},
+    anchor_lang::prelude::*,
+    anchor_lang::system_program::{transfer, Transfer},
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawEventRentPoolContext<'info> {
+    #[account()]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = owner @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: program-funded event_rent_pool PDA, system owned
+    #[account(
+        mut,
+        seeds = [b"event_rent_pool"],
+        bump
+    )]
+    pub event_rent_pool: UncheckedAccount<'info>,
+
+    /// CHECK: any account receiving the withdrawn lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct WithdrawEventRentPoolParams {
+    pub lamports: u64,
+}
+
+// Instruction handler
+pub fn withdraw_event_rent_pool(
+    ctx: Context<WithdrawEventRentPoolContext>,
+    params: &WithdrawEventRentPoolParams,
+) -> Result<()> {
+    let event_rent_seeds: &[&[&[u8]]] = &[&[b"event_rent_pool", &[ctx.bumps.event_rent_pool]]];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.event_rent_pool.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            },
+            event_rent_seeds,
+        ),
+        params.lamports,
+    )?;
+
+    emit_cpi!(EventRentPoolWithdrawn {
+        recipient: ctx.accounts.recipient.key(),
+        lamports: params.lamports,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//! WithdrawFees instruction handler
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::FeesWithdrawn,
+        state::{BridgeConfig, FeeLedger},
+        token_transfer,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+// Instruction accounts
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawFeesContext<'info> {
+    #[account()]
+    pub fee_manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        has_one = fee_manager @ ErrorCode::InvalidAuthority
+    )]
+    pub bridge_config: Box<Account<'info, BridgeConfig>>,
+
+    /// CHECK: empty PDA, owns the fee vault
+    #[account(
+        seeds = [b"integration_authority"],
+        bump = bridge_config.authority_bump
+    )]
+    pub integration_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fee_ledger",
+            fee_ledger.mint.as_ref()
+        ],
+        bump = fee_ledger.bump
+    )]
+    pub fee_ledger: Box<Account<'info, FeeLedger>>,
+
+    #[account(
+        address = fee_ledger.mint @ ErrorCode::InvalidMint
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fee_vault",
+            fee_ledger.mint.as_ref()
+        ],
+        bump = fee_ledger.fee_vault_bump
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == fee_ledger.mint @ ErrorCode::InvalidMint
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// Instruction parameters
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct WithdrawFeesParams {
+    pub amount: u64,
+}
+
+// Instruction handler
+pub fn withdraw_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFeesContext<'info>>,
+    params: &WithdrawFeesParams,
+) -> Result<()> {
+    require_gt!(params.amount, 0, ErrorCode::ZeroAmount);
+
+    let authority_seeds: &[&[&[u8]]] = &[&[
+        b"integration_authority",
+        &[ctx.accounts.bridge_config.authority_bump],
+    ]];
+
+    // Remaining accounts are forwarded to transfer_checked as the transfer hook accounts of the mint
+    token_transfer::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.fee_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.integration_authority.to_account_info(),
+            },
+            authority_seeds,
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        params.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let fee_ledger = ctx.accounts.fee_ledger.as_mut();
+    fee_ledger.record_withdrawn(params.amount)?;
+
+    emit_cpi!(FeesWithdrawn {
+        mint: fee_ledger.mint,
+        destination: ctx.accounts.destination.key(),
+        amount: params.amount,
+        total_withdrawn: fee_ledger.total_withdrawn,
+    });
+
+    Ok(())
+}
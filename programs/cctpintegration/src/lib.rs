@@ -1,8 +1,27 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, transfer};
-use token_messenger_minter_v2::token_messenger_v2::instructions::DepositForBurnParams;
-use token_messenger_minter_v2::cpi::accounts::DepositForBurnContext;
-use token_messenger_minter_v2::cpi::deposit_for_burn;
+//! Cctpintegration program entrypoint
+
+#![allow(unexpected_cfgs)]
+
+pub mod cctp;
+pub mod cctp_receive;
+pub mod error;
+pub mod events;
+pub mod fee_quote;
+pub mod hook_payload;
+pub mod instructions;
+pub mod partner_fee;
+pub mod state;
+pub mod swap_adapter;
+pub mod token_transfer;
+
+use {
+    anchor_lang::prelude::*,
+    fee_quote::FeeQuote,
+    instructions::*,
+    message_transmitter_v2::instructions::{ReceiveMessageParams, ReclaimEventAccountParams},
+};
+
+pub use error::ErrorCode;
 
 declare_id!("CABbkyFnKoZ9UpRnBu8YFaCBdBG1xMZPMuc6GmrnogbT");
 
@@ -10,162 +29,337 @@ declare_id!("CABbkyFnKoZ9UpRnBu8YFaCBdBG1xMZPMuc6GmrnogbT");
 pub mod cctpintegration {
     use super::*;
 
-    /// Transfer tokens with fee deduction to vault and fee recipient
-    pub fn bridge(ctx: Context<BridgeContext>, amount: u64, fee: u64) -> Result<()> {
-        require!(amount > fee, ErrorCode::InvalidFee);
-
-        // Transfer fee to the fee recipient
-        transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.user_usdc.to_account_info(),
-                    to: ctx.accounts.fee_usdc.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            fee,
-        )?;
-
-        // Transfer remaining amount to the vault
-        let bridge_amount = amount.checked_sub(fee).unwrap();   
-        transfer(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.user_usdc.to_account_info(),
-                    to: ctx.accounts.vault_usdc.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            bridge_amount,
-        )?;
-
-        Ok(())
-    }
-
-    /// Initiate cross-chain transfer via CCTP using proper CPI
-    pub fn deposit_for_burn_cctp(
-        ctx: Context<CctpTransfer>,
-        params: DepositForBurnParams,
-    ) -> Result<()> {
-        let cpi_accounts = DepositForBurnContext {
-            owner: ctx.accounts.user.to_account_info(),
-            event_rent_payer: ctx.accounts.event_rent_payer.to_account_info(),
-            sender_authority_pda: ctx.accounts.sender_authority_pda.to_account_info(),
-            burn_token_account: ctx.accounts.user_usdc.to_account_info(),
-            denylist_account: ctx.accounts.denylist_account.to_account_info(),
-            message_transmitter: ctx.accounts.message_transmitter.to_account_info(),
-            token_messenger: ctx.accounts.token_messenger.to_account_info(),
-            remote_token_messenger: ctx.accounts.remote_token_messenger.to_account_info(),
-            token_minter: ctx.accounts.token_minter.to_account_info(),
-            local_token: ctx.accounts.local_token.to_account_info(),
-            burn_token_mint: ctx.accounts.burn_token_mint.to_account_info(),
-            message_sent_event_data: ctx.accounts.message_sent_event_data.to_account_info(),
-            message_transmitter_program: ctx.accounts.message_transmitter_program.to_account_info(),
-            token_messenger_minter_program: ctx.accounts.token_messenger_minter_program.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            event_authority: ctx.accounts.event_authority.to_account_info(),
-            program: ctx.accounts.program.to_account_info(),
-        };
-    
-        let cpi_program = ctx.accounts.token_messenger_minter_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-        deposit_for_burn(cpi_ctx, params)?;
-        
-        msg!("CCTP deposit_for_burn executed successfully");
-        Ok(())
+    /// Create the bridge config with the accepted USDC mint, fee rate and custody accounts
+    pub fn initialize(ctx: Context<InitializeContext>, params: InitializeParams) -> Result<()> {
+        instructions::initialize(ctx, &params)
     }
-}
 
-#[derive(Accounts)]
-pub struct BridgeContext<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
+    /// Create the inbound token account that receives CCTP mints for receive_and_forward
+    pub fn initialize_inbound(ctx: Context<InitializeInboundContext>) -> Result<()> {
+        instructions::initialize_inbound(ctx)
+    }
 
-    #[account(mut)]
-    pub user_usdc: Account<'info, TokenAccount>,
+    /// Create the fee vault and fee ledger of a mint
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVaultContext>) -> Result<()> {
+        instructions::initialize_fee_vault(ctx)
+    }
 
-    #[account(mut)]
-    pub vault_usdc: Account<'info, TokenAccount>,
+    /// Withdraw collected fees from the fee vault
+    pub fn withdraw_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFeesContext<'info>>,
+        params: WithdrawFeesParams,
+    ) -> Result<()> {
+        instructions::withdraw_fees(ctx, &params)
+    }
 
-    #[account(mut)]
-    pub fee_usdc: Account<'info, TokenAccount>,
+    /// Start the two-step ownership transfer of the bridge config
+    pub fn transfer_ownership(
+        ctx: Context<TransferOwnershipContext>,
+        params: TransferOwnershipParams,
+    ) -> Result<()> {
+        instructions::transfer_ownership(ctx, &params)
+    }
 
-    pub token_program: Program<'info, Token>,
-}
+    /// Complete the ownership transfer, signed by the pending owner
+    pub fn accept_ownership(ctx: Context<AcceptOwnershipContext>) -> Result<()> {
+        instructions::accept_ownership(ctx)
+    }
 
-#[derive(Accounts)]
-pub struct CctpTransfer<'info> {
-    #[account(mut)]
-    pub user: Signer<'info>,
+    /// Replace the fee manager role
+    pub fn update_fee_manager(
+        ctx: Context<UpdateFeeManagerContext>,
+        params: UpdateFeeManagerParams,
+    ) -> Result<()> {
+        instructions::update_fee_manager(ctx, &params)
+    }
 
-    #[account(mut)]
-    pub user_usdc: Account<'info, TokenAccount>,
+    /// Replace the pauser role
+    pub fn update_pauser(
+        ctx: Context<UpdatePauserContext>,
+        params: UpdatePauserParams,
+    ) -> Result<()> {
+        instructions::update_pauser(ctx, &params)
+    }
 
-    /// CHECK: Event rent payer for CCTP events
-    #[account(mut)]
-    pub event_rent_payer: Signer<'info>,
+    /// Replace the domain manager role
+    pub fn update_domain_manager(
+        ctx: Context<UpdateDomainManagerContext>,
+        params: UpdateDomainManagerParams,
+    ) -> Result<()> {
+        instructions::update_domain_manager(ctx, &params)
+    }
 
-    /// CHECK: Sender authority PDA for CCTP
-    pub sender_authority_pda: AccountInfo<'info>,
+    /// Replace the compliance role
+    pub fn update_compliance(
+        ctx: Context<UpdateComplianceContext>,
+        params: UpdateComplianceParams,
+    ) -> Result<()> {
+        instructions::update_compliance(ctx, &params)
+    }
 
-    /// CHECK: Message transmitter account for CCTP
-    #[account(mut)]
-    pub message_transmitter: AccountInfo<'info>,
+    /// Stop bridge, deposit and receive entry points
+    pub fn pause(ctx: Context<PauseContext>) -> Result<()> {
+        instructions::pause(ctx)
+    }
 
-    /// CHECK: Token messenger account for CCTP
-    pub token_messenger: AccountInfo<'info>,
+    /// Resume bridge, deposit and receive entry points
+    pub fn unpause(ctx: Context<UnpauseContext>) -> Result<()> {
+        instructions::unpause(ctx)
+    }
 
-    /// CHECK: Remote token messenger account for CCTP
-    pub remote_token_messenger: AccountInfo<'info>,
+    /// Update the integration fee rate and its min/max caps
+    pub fn set_fee(ctx: Context<SetFeeContext>, params: SetFeeParams) -> Result<()> {
+        instructions::set_fee(ctx, &params)
+    }
 
-    /// CHECK: Token minter account for CCTP
-    pub token_minter: AccountInfo<'info>,
+    /// Update the canonical vault token account
+    pub fn set_fee_accounts(ctx: Context<SetFeeAccountsContext>) -> Result<()> {
+        instructions::set_fee_accounts(ctx)
+    }
 
-    /// CHECK: Local token account for CCTP
-    #[account(mut)]
-    pub local_token: AccountInfo<'info>,
+    /// Create or update the transfer policy of a destination domain
+    pub fn set_domain_config(
+        ctx: Context<SetDomainConfigContext>,
+        params: SetDomainConfigParams,
+    ) -> Result<()> {
+        instructions::set_domain_config(ctx, &params)
+    }
 
-    /// CHECK: Burn token mint account
-    #[account(mut)]
-    pub burn_token_mint: AccountInfo<'info>,
+    /// Create or update the sponsored deposit settings
+    pub fn set_sponsor_config(
+        ctx: Context<SetSponsorConfigContext>,
+        params: SetSponsorConfigParams,
+    ) -> Result<()> {
+        instructions::set_sponsor_config(ctx, &params)
+    }
 
-    /// CHECK: Message sent event data account
-    #[account(mut)]
-    pub message_sent_event_data: Signer<'info>,
+    /// Set the key whose Ed25519 signatures price quoted bridges and deposits
+    pub fn set_quote_signer(
+        ctx: Context<SetQuoteSignerContext>,
+        params: SetQuoteSignerParams,
+    ) -> Result<()> {
+        instructions::set_quote_signer(ctx, &params)
+    }
 
-    /// CHECK: Message transmitter program
-    pub message_transmitter_program: AccountInfo<'info>,
+    /// Register the program executed for inbound hooks of a hook type id
+    pub fn register_hook(
+        ctx: Context<RegisterHookContext>,
+        params: RegisterHookParams,
+    ) -> Result<()> {
+        instructions::register_hook(ctx, &params)
+    }
 
-    /// CHECK: Token messenger minter program
-    pub token_messenger_minter_program: AccountInfo<'info>,
+    /// Remove a hook registration
+    pub fn unregister_hook(ctx: Context<UnregisterHookContext>) -> Result<()> {
+        instructions::unregister_hook(ctx)
+    }
 
-    pub token_program: Program<'info, Token>,
+    /// Allow a relayer to complete relayed transfers
+    pub fn add_relayer(ctx: Context<AddRelayerContext>, params: AddRelayerParams) -> Result<()> {
+        instructions::add_relayer(ctx, &params)
+    }
 
-    /// CHECK: System program
-    pub system_program: AccountInfo<'info>,
+    /// Remove a relayer
+    pub fn remove_relayer(ctx: Context<RemoveRelayerContext>) -> Result<()> {
+        instructions::remove_relayer(ctx)
+    }
 
-    /// CHECK: Event authority for CCTP
-    pub event_authority: AccountInfo<'info>,
+    /// Refuse an address as depositor, mint recipient and inbound recipient
+    pub fn block_address(
+        ctx: Context<BlockAddressContext>,
+        params: BlockAddressParams,
+    ) -> Result<()> {
+        instructions::block_address(ctx, &params)
+    }
 
-    /// CHECK: Program account
-    pub program: AccountInfo<'info>,
+    /// Lift a block placed by block_address
+    pub fn unblock_address(ctx: Context<UnblockAddressContext>) -> Result<()> {
+        instructions::unblock_address(ctx)
+    }
 
-    /// CHECK: Denylist account for CCTP
-    pub denylist_account: AccountInfo<'info>,
-}
+    /// Set the discounted fee rates applied by rolling 30-day user volume
+    pub fn set_fee_tiers(
+        ctx: Context<SetFeeTiersContext>,
+        params: SetFeeTiersParams,
+    ) -> Result<()> {
+        instructions::set_fee_tiers(ctx, &params)
+    }
+
+    /// Set the fee shares paid to the token accounts of a partner
+    pub fn set_partner_config(
+        ctx: Context<SetPartnerConfigContext>,
+        params: SetPartnerConfigParams,
+    ) -> Result<()> {
+        instructions::set_partner_config(ctx, &params)
+    }
+
+    /// Set the swap program used by swap_and_deposit_for_burn_cctp
+    pub fn set_swap_config(
+        ctx: Context<SetSwapConfigContext>,
+        params: SetSwapConfigParams,
+    ) -> Result<()> {
+        instructions::set_swap_config(ctx, &params)
+    }
+
+    /// Quote the integration fee, CCTP fees and minted amount of a bridge_and_deposit_for_burn
+    pub fn quote_transfer(
+        ctx: Context<QuoteTransferContext>,
+        params: QuoteTransferParams,
+    ) -> Result<TransferQuote> {
+        instructions::quote_transfer(ctx, &params)
+    }
+
+    /// Transfer tokens with fee deduction to vault and fee recipient, sharing the fee with an optional partner
+    /// or charging the fee of an optional signed fee quote
+    pub fn bridge<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BridgeContext<'info>>,
+        amount: u64,
+        partner_id: Option<u16>,
+        quote: Option<FeeQuote>,
+    ) -> Result<()> {
+        instructions::bridge(ctx, amount, partner_id, quote)
+    }
 
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Fee must be less than total amount")]
-    InvalidFee,
-    #[msg("Invalid destination domain")]
-    InvalidDestinationDomain,
-    #[msg("Invalid recipient address")]
-    InvalidRecipient,
-    #[msg("CCTP transfer failed")]
-    CctpTransferFailed,
-}
\ No newline at end of file
+    /// Collect the integration fee and burn the remainder via CCTP in a single instruction
+    pub fn bridge_and_deposit_for_burn<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BridgeAndDepositForBurnContext<'info>>,
+        params: BridgeAndDepositForBurnParams,
+    ) -> Result<()> {
+        instructions::bridge_and_deposit_for_burn(ctx, &params)
+    }
+
+    /// Initiate cross-chain transfer via CCTP using proper CPI, charging the integration fee
+    /// of an optional signed fee quote
+    pub fn deposit_for_burn_cctp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DepositForBurnCctpContext<'info>>,
+        params: DepositForBurnCctpParams,
+        quote: Option<FeeQuote>,
+    ) -> Result<()> {
+        instructions::deposit_for_burn_cctp(ctx, &params, quote)
+    }
+
+    /// Initiate cross-chain transfer via CCTP at a TransferSpeed, finality and max fee set by the wrapper
+    pub fn deposit_for_burn_with_speed_cctp(
+        ctx: Context<DepositForBurnWithSpeedCctpContext>,
+        params: DepositForBurnWithSpeedCctpParams,
+    ) -> Result<()> {
+        instructions::deposit_for_burn_with_speed_cctp(ctx, &params)
+    }
+
+    /// Initiate cross-chain transfer via CCTP with a cctpintegration hook payload
+    pub fn deposit_for_burn_with_hook_cctp(
+        ctx: Context<DepositForBurnWithHookCctpContext>,
+        params: DepositForBurnWithHookCctpParams,
+    ) -> Result<()> {
+        instructions::deposit_for_burn_with_hook_cctp(ctx, &params)
+    }
+
+    /// Receive a CCTP message into the inbound account and forward the minted funds
+    /// to the recipient named by the hook payload
+    pub fn receive_and_forward(
+        ctx: Context<ReceiveAndForwardContext>,
+        params: ReceiveMessageParams,
+    ) -> Result<()> {
+        instructions::receive_and_forward(ctx, &params)
+    }
+
+    /// Move USDC stranded in the inbound account, amounts reserved for pending hooks excluded
+    pub fn recover_inbound(
+        ctx: Context<RecoverInboundContext>,
+        params: RecoverInboundParams,
+    ) -> Result<()> {
+        instructions::recover_inbound(ctx, &params)
+    }
+
+    /// Receive a CCTP message carrying a registered hook and record it for execute_hook
+    pub fn receive_for_hook(
+        ctx: Context<ReceiveForHookContext>,
+        params: ReceiveMessageParams,
+    ) -> Result<()> {
+        instructions::receive_for_hook(ctx, &params)
+    }
+
+    /// Hand a pending inbound transfer to its registered hook program, callable by anyone
+    pub fn execute_hook<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteHookContext<'info>>,
+    ) -> Result<()> {
+        instructions::execute_hook(ctx)
+    }
+
+    /// Refund a pending hook whose registration was removed to an owner-chosen token account
+    pub fn cancel_pending_hook(ctx: Context<CancelPendingHookContext>) -> Result<()> {
+        instructions::cancel_pending_hook(ctx)
+    }
+
+    /// Deposit for burn addressed to relayer_authority, with a relayer fee paid on the destination
+    pub fn deposit_for_burn_relayed_cctp(
+        ctx: Context<DepositForBurnRelayedCctpContext>,
+        params: DepositForBurnRelayedCctpParams,
+    ) -> Result<()> {
+        instructions::deposit_for_burn_relayed_cctp(ctx, &params)
+    }
+
+    /// Complete a relayed transfer, paying the relayer fee and forwarding the rest to the recipient
+    pub fn relay_and_forward(
+        ctx: Context<RelayAndForwardContext>,
+        params: ReceiveMessageParams,
+    ) -> Result<()> {
+        instructions::relay_and_forward(ctx, &params)
+    }
+
+    /// Burn for several recipients in one transaction, one CCTP message per entry
+    pub fn batch_deposit_for_burn<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchDepositForBurnContext<'info>>,
+        params: BatchDepositForBurnParams,
+    ) -> Result<()> {
+        instructions::batch_deposit_for_burn(ctx, &params)
+    }
+
+    /// Add lamports to the pool that pays MessageSent event account rent
+    pub fn fund_event_rent_pool(
+        ctx: Context<FundEventRentPoolContext>,
+        params: FundEventRentPoolParams,
+    ) -> Result<()> {
+        instructions::fund_event_rent_pool(ctx, &params)
+    }
+
+    /// Withdraw lamports from the event rent pool
+    pub fn withdraw_event_rent_pool(
+        ctx: Context<WithdrawEventRentPoolContext>,
+        params: WithdrawEventRentPoolParams,
+    ) -> Result<()> {
+        instructions::withdraw_event_rent_pool(ctx, &params)
+    }
+
+    /// Mark a pending transfer record as attested once the attestation of its message is verified
+    pub fn mark_transfer_attested(
+        ctx: Context<MarkTransferAttestedContext>,
+        params: ReclaimEventAccountParams,
+    ) -> Result<()> {
+        instructions::mark_transfer_attested(ctx, &params)
+    }
+
+    /// Close an attested MessageSent account and return its rent to the rent payer
+    pub fn reclaim_event_account(
+        ctx: Context<ReclaimEventAccountContext>,
+        params: ReclaimEventAccountParams,
+    ) -> Result<()> {
+        instructions::reclaim_event_account(ctx, &params)
+    }
+
+    /// Deposit for burn with SOL costs paid by the fee-payer service and charged to the user in USDC
+    pub fn deposit_for_burn_sponsored_cctp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DepositForBurnSponsoredCctpContext<'info>>,
+        params: DepositForBurnSponsoredCctpParams,
+    ) -> Result<()> {
+        instructions::deposit_for_burn_sponsored_cctp(ctx, &params)
+    }
+
+    /// Swap a non-USDC input through the configured swap program and deposit the USDC received for burn
+    pub fn swap_and_deposit_for_burn_cctp<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SwapAndDepositForBurnCctpContext<'info>>,
+        params: SwapAndDepositForBurnCctpParams,
+    ) -> Result<()> {
+        instructions::swap_and_deposit_for_burn_cctp(ctx, &params)
+    }
+}
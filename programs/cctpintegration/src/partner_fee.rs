@@ -0,0 +1,158 @@
+//! PartnerFee
+
+use {
+    crate::{
+        error::ErrorCode,
+        events::PartnerFeePaid,
+        state::{FeeLedger, PartnerConfig},
+        token_transfer,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token_interface::{TokenAccount, TransferChecked},
+};
+
+/// Partner share of an integration fee paid on a bridge path
+#[derive(Clone, Copy, Debug)]
+pub struct PartnerFeePayment {
+    pub token_account: Pubkey,
+    pub share_bps: u16,
+    // Taken from the user
+    pub sent: u64,
+    // Credited to token_account, after the Token-2022 transfer fee of the mint
+    pub received: u64,
+}
+
+impl PartnerFeePayment {
+    /// Returns the PartnerFeePaid event of this payment
+    pub fn event(
+        &self,
+        partner_id: u16,
+        depositor: Pubkey,
+        mint: Pubkey,
+        destination_domain: Option<u32>,
+    ) -> PartnerFeePaid {
+        PartnerFeePaid {
+            partner_id,
+            depositor,
+            mint,
+            token_account: self.token_account,
+            share_bps: self.share_bps,
+            amount: self.received,
+            destination_domain,
+        }
+    }
+}
+
+/// Checks that the partner config matches the partner id of the instruction and splits the
+/// remaining accounts into the partner token accounts, in share order, and the transfer hook accounts
+pub fn split_remaining_accounts<'info>(
+    partner_config: Option<&PartnerConfig>,
+    partner_id: Option<u16>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<(&'info [AccountInfo<'info>], &'info [AccountInfo<'info>])> {
+    match (partner_config, partner_id) {
+        (None, None) => Ok((&[], remaining_accounts)),
+        (Some(partner_config), Some(partner_id)) => {
+            require_eq!(
+                partner_config.partner_id,
+                partner_id,
+                ErrorCode::InvalidPartnerConfig
+            );
+            require_gte!(
+                remaining_accounts.len(),
+                partner_config.shares.len(),
+                ErrorCode::InvalidPartnerAccount
+            );
+            Ok(remaining_accounts.split_at(partner_config.shares.len()))
+        }
+        _ => err!(ErrorCode::InvalidPartnerConfig),
+    }
+}
+
+/// Accounts of the fee transfers out of the user token account, shared by every share
+pub struct FeeTransfer<'info> {
+    pub token_program: AccountInfo<'info>,
+    pub from: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub decimals: u8,
+    // Forwarded to transfer_checked as the transfer hook accounts of the mint
+    pub transfer_hook_accounts: &'info [AccountInfo<'info>],
+}
+
+impl<'info> FeeTransfer<'info> {
+    /// Returns the transfer_checked context of a fee transfer to `to`
+    pub fn context(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        CpiContext::new(
+            self.token_program.clone(),
+            TransferChecked {
+                from: self.from.clone(),
+                mint: self.mint.clone(),
+                to,
+                authority: self.authority.clone(),
+            },
+        )
+        .with_remaining_accounts(self.transfer_hook_accounts.to_vec())
+    }
+}
+
+/// Pays every partner share of `fee` and records it in the fee ledger,
+/// the caller sends the rest of the fee to the fee vault
+pub fn pay_partner_shares<'info>(
+    partner_config: &PartnerConfig,
+    fee_ledger: &mut FeeLedger,
+    transfer: &FeeTransfer<'info>,
+    partner_accounts: &'info [AccountInfo<'info>],
+    fee: u64,
+) -> Result<Vec<PartnerFeePayment>> {
+    let mut payments = Vec::with_capacity(partner_config.shares.len());
+
+    for (share, account) in partner_config.shares.iter().zip(partner_accounts) {
+        require_keys_eq!(
+            account.key(),
+            share.token_account,
+            ErrorCode::InvalidPartnerAccount
+        );
+        let mut partner_token_account = InterfaceAccount::<TokenAccount>::try_from(account)?;
+        require_keys_eq!(
+            partner_token_account.mint,
+            transfer.mint.key(),
+            ErrorCode::InvalidMint
+        );
+
+        let sent = partner_config.get_share_amount(fee, share);
+        if sent == 0 {
+            continue;
+        }
+
+        let received = token_transfer::transfer_checked_net(
+            transfer.context(account.clone()),
+            &mut partner_token_account,
+            sent,
+            transfer.decimals,
+        )?;
+        fee_ledger.record_partner_paid(partner_config.partner_id, received)?;
+
+        payments.push(PartnerFeePayment {
+            token_account: share.token_account,
+            share_bps: share.share_bps,
+            sent,
+            received,
+        });
+    }
+
+    Ok(payments)
+}
+
+/// Returns the part of the fee taken by the partners
+pub fn total_sent(payments: &[PartnerFeePayment]) -> u64 {
+    payments.iter().map(|p| p.sent).sum()
+}
+
+/// Returns the part of the fee credited to the partners
+pub fn total_received(payments: &[PartnerFeePayment]) -> u64 {
+    payments.iter().map(|p| p.received).sum()
+}
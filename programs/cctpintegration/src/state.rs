@@ -0,0 +1,978 @@
+//! State
+
+use {
+    crate::error::ErrorCode, anchor_lang::prelude::*, message_transmitter_v2::message::Message,
+    token_messenger_minter_v2::token_messenger_v2::burn_message::BurnMessage,
+};
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Fee and custody settings used by the bridge instruction
+pub struct BridgeConfig {
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    // Updates fee rates, fee accounts and sponsored deposit settings
+    pub fee_manager: Pubkey,
+    pub pauser: Pubkey,
+    // Updates destination domain policies
+    pub domain_manager: Pubkey,
+    // Blocks and unblocks screened addresses
+    pub compliance: Pubkey,
+    // Blocks bridge, deposit and receive entry points, admin and withdrawal paths stay usable
+    pub paused: bool,
+    pub usdc_mint: Pubkey,
+    pub fee_bps: u16,
+    pub min_fee_amount: u64,
+    pub max_fee_amount: u64,
+    // Discounted rates by rolling volume, ordered by min_volume. Tier 0 is fee_bps, tier i is fee_tiers[i - 1]
+    #[max_len(4)]
+    pub fee_tiers: Vec<FeeTier>,
+    pub vault_usdc: Pubkey,
+    // Signs fee quotes of bridge and deposit_for_burn_cctp, default disables them
+    pub quote_signer: Pubkey,
+    // Held in inbound_usdc for received hooks that are not executed yet, never recoverable
+    pub inbound_reserved: u64,
+    pub bump: u8,
+    pub authority_bump: u8,
+    pub staging_bump: u8,
+    pub inbound_bump: u8,
+    pub relayer_authority_bump: u8,
+}
+
+impl BridgeConfig {
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+    // Must match the max_len of fee_tiers
+    pub const MAX_FEE_TIERS: usize = 4;
+
+    /// Checks if the state is valid
+    pub fn validate(&self) -> bool {
+        self.owner != Pubkey::default()
+            && self.fee_manager != Pubkey::default()
+            && self.pauser != Pubkey::default()
+            && self.domain_manager != Pubkey::default()
+            && self.compliance != Pubkey::default()
+            && self.usdc_mint != Pubkey::default()
+            && self.vault_usdc != Pubkey::default()
+            && self.fee_bps as u64 <= Self::BPS_DENOMINATOR
+            && self.min_fee_amount <= self.max_fee_amount
+            && self.fee_tiers.len() <= Self::MAX_FEE_TIERS
+            && self
+                .fee_tiers
+                .iter()
+                .all(|t| t.fee_bps as u64 <= Self::BPS_DENOMINATOR)
+            && self
+                .fee_tiers
+                .windows(2)
+                .all(|w| w[0].min_volume < w[1].min_volume)
+    }
+
+    /// Returns the part of the inbound balance not reserved for pending hooks
+    pub fn recoverable_inbound_amount(&self, inbound_balance: u64) -> u64 {
+        inbound_balance.saturating_sub(self.inbound_reserved)
+    }
+
+    /// Calculates the integration fee for a given amount, capped to [min_fee_amount, max_fee_amount]
+    pub fn get_fee_amount(&self, amount: u64) -> Result<u64> {
+        self.get_tiered_fee_amount(amount, 0)
+    }
+
+    /// Returns the fee tier of a user with the given rolling volume, 0 is the base fee_bps
+    pub fn get_fee_tier(&self, volume: u64) -> u8 {
+        self.fee_tiers
+            .iter()
+            .rposition(|t| volume >= t.min_volume)
+            .map_or(0, |i| i as u8 + 1)
+    }
+
+    /// Calculates the integration fee at the rate of a fee tier, capped to [min_fee_amount, max_fee_amount]
+    pub fn get_tiered_fee_amount(&self, amount: u64, tier: u8) -> Result<u64> {
+        let fee_bps = match tier.checked_sub(1) {
+            None => self.fee_bps,
+            Some(index) => {
+                self.fee_tiers
+                    .get(index as usize)
+                    .ok_or_else(|| error!(ErrorCode::InvalidFee))?
+                    .fee_bps
+            }
+        };
+
+        // fee_bps never exceeds BPS_DENOMINATOR, so the result always fits back into u64
+        let fee = (amount as u128 * fee_bps as u128 / Self::BPS_DENOMINATOR as u128) as u64;
+        let fee = fee.clamp(self.min_fee_amount, self.max_fee_amount);
+
+        require_gt!(amount, fee, ErrorCode::InvalidFee);
+
+        Ok(fee)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct FeeTier {
+    // Smallest rolling volume of the tier, in USDC base units
+    pub min_volume: u64,
+    pub fee_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct HookAccountMeta {
+    // Pubkey::default() accepts any account supplied by the executor
+    pub pubkey: Pubkey,
+    pub is_writable: bool,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Allowlisted program executed for inbound hooks of a given hook type id
+pub struct HookRegistration {
+    pub hook_id: u8,
+    pub target_program: Pubkey,
+    // Token account of the target program that receives the minted amount before the CPI
+    pub token_account: Pubkey,
+    #[max_len(16)]
+    pub accounts: Vec<HookAccountMeta>,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Inbound transfer minted into the inbound token account and waiting for execute_hook
+pub struct PendingHook {
+    pub rent_payer: Pubkey,
+    pub hook_id: u8,
+    pub amount: u64,
+    pub source_domain: u32,
+    pub nonce: [u8; 32],
+    #[max_len(1)]
+    pub hook_payload: Vec<u8>,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Relayer allowed to complete messages addressed to relayer_authority
+pub struct Relayer {
+    pub relayer: Pubkey,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum DomainType {
+    /// 20-byte addresses left-padded with zeros to 32 bytes
+    Evm,
+    /// 32-byte public keys, mint_recipient is a token account
+    Solana,
+    /// No address format checks beyond a non-default key
+    Other,
+}
+
+impl DomainType {
+    /// Number of leading zero bytes in a left-padded EVM address
+    pub const EVM_ADDRESS_PADDING: usize = 12;
+
+    /// Checks that mint_recipient is a well-formed address for the domain type
+    pub fn is_valid_mint_recipient(&self, mint_recipient: &Pubkey) -> bool {
+        if *mint_recipient == Pubkey::default() {
+            return false;
+        }
+        match self {
+            DomainType::Evm => Self::is_padded_evm_address(mint_recipient),
+            // Any token account can receive the mint, resolving a wallet into its token account
+            // is left to the client as keypair token accounts are on-curve like wallets
+            DomainType::Solana | DomainType::Other => true,
+        }
+    }
+
+    /// Checks that destination_caller is either unset or a well-formed address for the domain type
+    pub fn is_valid_destination_caller(&self, destination_caller: &Pubkey) -> bool {
+        if *destination_caller == Pubkey::default() {
+            return true;
+        }
+        match self {
+            DomainType::Evm => Self::is_padded_evm_address(destination_caller),
+            DomainType::Solana | DomainType::Other => true,
+        }
+    }
+
+    fn is_padded_evm_address(address: &Pubkey) -> bool {
+        address.as_ref()[..Self::EVM_ADDRESS_PADDING]
+            .iter()
+            .all(|byte| *byte == 0)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct DomainFeeTotal {
+    pub domain: u32,
+    pub collected: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct PartnerFeeTotal {
+    pub partner_id: u16,
+    pub paid: u64,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Lifetime fee accounting of the fee vault of a mint
+pub struct FeeLedger {
+    pub mint: Pubkey,
+    pub fee_vault: Pubkey,
+    pub total_collected: u64,
+    pub total_withdrawn: u64,
+    // Fees collected with a known destination domain, bridge fees are only counted in total_collected
+    #[max_len(32)]
+    pub domain_totals: Vec<DomainFeeTotal>,
+    // Fee shares paid directly to partner token accounts, never held by the fee vault
+    pub total_partner_paid: u64,
+    #[max_len(32)]
+    pub partner_totals: Vec<PartnerFeeTotal>,
+    pub bump: u8,
+    pub fee_vault_bump: u8,
+}
+
+impl FeeLedger {
+    // Must match the max_len of domain_totals
+    pub const MAX_DOMAINS: usize = 32;
+    // Must match the max_len of partner_totals
+    pub const MAX_PARTNERS: usize = 32;
+
+    /// Adds collected fees to the lifetime and per-domain totals
+    pub fn record_collected(&mut self, domain: Option<u32>, amount: u64) -> Result<()> {
+        self.total_collected = self
+            .total_collected
+            .checked_add(amount)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+
+        let Some(domain) = domain else {
+            return Ok(());
+        };
+        if let Some(total) = self.domain_totals.iter_mut().find(|t| t.domain == domain) {
+            total.collected = total
+                .collected
+                .checked_add(amount)
+                .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+        } else {
+            require_gt!(
+                Self::MAX_DOMAINS,
+                self.domain_totals.len(),
+                ErrorCode::InvalidFeeLedger
+            );
+            self.domain_totals.push(DomainFeeTotal {
+                domain,
+                collected: amount,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Adds a fee share paid to a partner to the lifetime and per-partner totals
+    pub fn record_partner_paid(&mut self, partner_id: u16, amount: u64) -> Result<()> {
+        self.total_partner_paid = self
+            .total_partner_paid
+            .checked_add(amount)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+
+        if let Some(total) = self
+            .partner_totals
+            .iter_mut()
+            .find(|t| t.partner_id == partner_id)
+        {
+            total.paid = total
+                .paid
+                .checked_add(amount)
+                .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+        } else {
+            require_gt!(
+                Self::MAX_PARTNERS,
+                self.partner_totals.len(),
+                ErrorCode::InvalidFeeLedger
+            );
+            self.partner_totals.push(PartnerFeeTotal {
+                partner_id,
+                paid: amount,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Adds withdrawn fees to the lifetime total
+    pub fn record_withdrawn(&mut self, amount: u64) -> Result<()> {
+        self.total_withdrawn = self
+            .total_withdrawn
+            .checked_add(amount)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Per destination domain transfer policy, deposits to domains without a DomainConfig are rejected
+pub struct DomainConfig {
+    pub domain: u32,
+    pub domain_type: DomainType,
+    pub enabled: bool,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    // Used when a deposit does not set min_finality_threshold
+    pub default_min_finality_threshold: u32,
+    // Used when a deposit does not set max_fee
+    pub default_max_fee: u64,
+    // Added to TokenMessenger min fee to get the max_fee of deposits that select a TransferSpeed
+    pub max_fee_buffer: u64,
+    // Largest amount accepted below finalized finality, 0 disables fast transfers to the domain
+    pub max_fast_amount: u64,
+    // Inbound token account of cctpintegration on the domain, default if it is not deployed there
+    pub inbound_usdc: Pubkey,
+    pub bump: u8,
+}
+
+impl DomainConfig {
+    /// Checks if the state is valid
+    pub fn validate(&self) -> bool {
+        self.max_amount > 0 && self.min_amount <= self.max_amount
+    }
+
+    /// Returns true if the amount is within the domain bounds
+    pub fn is_amount_allowed(&self, amount: u64) -> bool {
+        (self.min_amount..=self.max_amount).contains(&amount)
+    }
+
+    /// Returns true if mint_recipient is the cctpintegration inbound token account of the domain
+    pub fn is_inbound_recipient(&self, mint_recipient: &Pubkey) -> bool {
+        self.inbound_usdc != Pubkey::default() && *mint_recipient == self.inbound_usdc
+    }
+
+    /// Returns the requested finality threshold, or the domain default if none was requested
+    pub fn min_finality_threshold(&self, requested: Option<u32>) -> u32 {
+        requested.unwrap_or(self.default_min_finality_threshold)
+    }
+
+    /// Returns the requested max fee, or the domain default if none was requested
+    pub fn max_fee(&self, requested: Option<u64>) -> u64 {
+        requested.unwrap_or(self.default_max_fee)
+    }
+
+    /// Returns the max fee of a deposit selecting a TransferSpeed, CCTP min fee plus the domain buffer
+    pub fn max_fee_with_buffer(&self, min_fee_amount: u64) -> Result<u64> {
+        min_fee_amount
+            .checked_add(self.max_fee_buffer)
+            .ok_or_else(|| error!(ErrorCode::InvalidDomainConfig))
+    }
+
+    /// Returns false for fast transfers above the domain fast transfer cap
+    pub fn is_finality_allowed(&self, min_finality_threshold: u32, amount: u64) -> bool {
+        match TransferSpeed::from_finality_threshold(min_finality_threshold) {
+            TransferSpeed::Fast => amount <= self.max_fast_amount,
+            TransferSpeed::Standard => true,
+        }
+    }
+}
+
+/// CCTP finality tier, selects the min_finality_threshold of a deposit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum TransferSpeed {
+    // Attested at confirmed finality, for a higher fee
+    Fast,
+    // Attested at finalized finality
+    Standard,
+}
+
+impl TransferSpeed {
+    pub const FAST_FINALITY_THRESHOLD: u32 = 1000;
+    pub const FINALIZED_FINALITY_THRESHOLD: u32 = 2000;
+
+    /// Returns the min_finality_threshold passed to TokenMessengerMinter
+    pub fn min_finality_threshold(&self) -> u32 {
+        match self {
+            TransferSpeed::Fast => Self::FAST_FINALITY_THRESHOLD,
+            TransferSpeed::Standard => Self::FINALIZED_FINALITY_THRESHOLD,
+        }
+    }
+
+    /// Returns the speed of a raw threshold, anything below finalized is attested fast
+    pub fn from_finality_threshold(min_finality_threshold: u32) -> Self {
+        if min_finality_threshold < Self::FINALIZED_FINALITY_THRESHOLD {
+            TransferSpeed::Fast
+        } else {
+            TransferSpeed::Standard
+        }
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Screened address refused as depositor, mint recipient and inbound recipient while it exists
+pub struct BlockedAddress {
+    pub address: Pubkey,
+    pub blocked_at: i64,
+    pub bump: u8,
+}
+
+impl BlockedAddress {
+    /// Checks that `account` is the BlockedAddress PDA of `address` and that it does not exist
+    pub fn require_not_blocked(account: &AccountInfo, address: &Pubkey) -> Result<()> {
+        let (expected, _) =
+            Pubkey::find_program_address(&[b"blocked_address", address.as_ref()], &crate::ID);
+        require_keys_eq!(account.key(), expected, ErrorCode::InvalidBlockedAddress);
+        require!(account.data_is_empty(), ErrorCode::AddressBlocked);
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Marks a fee quote as used, created from its quote id on first use
+pub struct UsedQuote {
+    pub quote_id: [u8; 32],
+    pub user: Pubkey,
+    pub used_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct PartnerShare {
+    pub token_account: Pubkey,
+    pub share_bps: u16,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Split of the integration fee with wallet partners, selected by partner id on bridge paths
+pub struct PartnerConfig {
+    pub partner_id: u16,
+    pub enabled: bool,
+    // Share of the integration fee paid to each token account, the rest goes to the fee vault
+    #[max_len(4)]
+    pub shares: Vec<PartnerShare>,
+    pub bump: u8,
+}
+
+impl PartnerConfig {
+    // Must match the max_len of shares
+    pub const MAX_SHARES: usize = 4;
+
+    /// Checks if the state is valid
+    pub fn validate(&self) -> bool {
+        !self.shares.is_empty()
+            && self.shares.len() <= Self::MAX_SHARES
+            && self
+                .shares
+                .iter()
+                .all(|s| s.token_account != Pubkey::default() && s.share_bps > 0)
+            && self.shares.iter().map(|s| s.share_bps as u64).sum::<u64>()
+                <= BridgeConfig::BPS_DENOMINATOR
+    }
+
+    /// Returns the part of `fee` owed to `share`, rounded down in favor of the fee vault
+    pub fn get_share_amount(&self, fee: u64, share: &PartnerShare) -> u64 {
+        // share_bps never exceeds BPS_DENOMINATOR, so the result always fits back into u64
+        (fee as u128 * share.share_bps as u128 / BridgeConfig::BPS_DENOMINATOR as u128) as u64
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Swap program called by swap_and_deposit_for_burn_cctp through the swap adapter interface
+pub struct SwapConfig {
+    pub enabled: bool,
+    pub swap_program: Pubkey,
+    pub bump: u8,
+}
+
+impl SwapConfig {
+    /// Checks if the state is valid
+    pub fn validate(&self) -> bool {
+        ![
+            Pubkey::default(),
+            crate::ID,
+            message_transmitter_v2::ID,
+            token_messenger_minter_v2::ID,
+        ]
+        .contains(&self.swap_program)
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Settings of sponsored deposits, where a fee-payer service covers SOL costs for a USDC surcharge
+pub struct SponsorConfig {
+    pub enabled: bool,
+    // Service key that signs and pays for sponsored transactions
+    pub fee_payer: Pubkey,
+    // USDC base units charged per 1 SOL (LAMPORTS_PER_SOL lamports) spent by the sponsor
+    pub usdc_per_sol: u64,
+    // Transaction fee paid by fee_payer, not observable on-chain
+    pub tx_fee_lamports: u64,
+    pub bump: u8,
+}
+
+impl SponsorConfig {
+    pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+    /// Checks if the state is valid
+    pub fn validate(&self) -> bool {
+        self.fee_payer != Pubkey::default() && self.usdc_per_sol > 0
+    }
+
+    /// Converts spent lamports into the USDC surcharge, rounded up in favor of the sponsor
+    pub fn get_surcharge_amount(&self, lamports: u64) -> Result<u64> {
+        let lamports = lamports
+            .checked_add(self.tx_fee_lamports)
+            .ok_or_else(|| error!(ErrorCode::InvalidSponsorConfig))?;
+        let surcharge =
+            (lamports as u128 * self.usdc_per_sol as u128).div_ceil(Self::LAMPORTS_PER_SOL as u128);
+        u64::try_from(surcharge).map_err(|_| error!(ErrorCode::InvalidSponsorConfig))
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Per-user counter used to derive TransferRecord addresses
+pub struct UserTransferCounter {
+    pub user: Pubkey,
+    pub next_index: u64,
+    // MessageSent accounts of the user paid by event_rent_pool and not reclaimed yet
+    pub subsidized_pending: u8,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Per-user bridged volume over a rolling window of daily buckets, selects the fee tier
+pub struct UserStats {
+    pub user: Pubkey,
+    // Bridged volume per day, indexed by unix day modulo WINDOW_DAYS
+    pub daily_volume: [u64; UserStats::WINDOW_DAYS],
+    // Unix day of the last recorded transfer
+    pub last_day: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum TransferStatus {
+    /// Burned on the source, waiting for the attestation
+    Pending,
+    /// Attestation of the message verified by mark_transfer_attested
+    Attested,
+    /// message_sent_event_data was reclaimed with the attestation of the message
+    Reclaimed,
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+/// Outbound transfer made through the wrapper.
+///
+/// Fields before the first variable offset are fixed, so records can be listed with
+/// getProgramAccounts memcmp filters: user at USER_OFFSET, status at STATUS_OFFSET.
+pub struct TransferRecord {
+    pub user: Pubkey,
+    pub index: u64,
+    pub status: TransferStatus,
+    pub amount: u64,
+    pub integration_fee: u64,
+    pub destination_domain: u32,
+    pub mint_recipient: Pubkey,
+    pub message_sent_event_data: Pubkey,
+    pub min_finality_threshold: u32,
+    pub created_at: i64,
+    // Set by mark_transfer_attested, stays 0 for records reclaimed straight from Pending
+    pub attested_at: i64,
+    pub reclaimed_at: i64,
+    pub bump: u8,
+}
+
+impl UserTransferCounter {
+    // Largest number of pool-paid event accounts a user may have pending reclaim
+    pub const MAX_SUBSIDIZED_PENDING: u8 = 4;
+
+    /// Returns the index for the next TransferRecord of the user and advances the counter
+    pub fn next(&mut self, user: Pubkey, bump: u8) -> Result<u64> {
+        if self.user == Pubkey::default() {
+            self.user = user;
+            self.bump = bump;
+        }
+        let index = self.next_index;
+        self.next_index = index
+            .checked_add(1)
+            .ok_or_else(|| error!(ErrorCode::MathOverflow))?;
+        Ok(index)
+    }
+
+    /// Counts an event account paid by event_rent_pool if the pool holds `required_lamports`
+    /// and the user is below MAX_SUBSIDIZED_PENDING. Returns false when the payer pays instead
+    pub fn start_subsidy(&mut self, pool_lamports: u64, required_lamports: u64) -> bool {
+        if pool_lamports < required_lamports
+            || self.subsidized_pending >= Self::MAX_SUBSIDIZED_PENDING
+        {
+            return false;
+        }
+        self.subsidized_pending += 1;
+        true
+    }
+
+    /// Releases a pool-paid event account once its rent is back in event_rent_pool
+    pub fn end_subsidy(&mut self) {
+        self.subsidized_pending = self.subsidized_pending.saturating_sub(1);
+    }
+}
+
+impl UserStats {
+    pub const WINDOW_DAYS: usize = 30;
+    pub const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// Returns the volume bridged over the WINDOW_DAYS days ending on the day of `timestamp`.
+    /// A timestamp before last_day is clamped to last_day, the day record books it on
+    pub fn rolling_volume(&self, timestamp: i64) -> u64 {
+        let day = timestamp
+            .div_euclid(Self::SECONDS_PER_DAY)
+            .max(self.last_day);
+        let first_day = day - (Self::WINDOW_DAYS as i64 - 1);
+
+        (first_day..=self.last_day)
+            .map(|d| self.daily_volume[Self::bucket(d)])
+            .fold(0u64, u64::saturating_add)
+    }
+
+    /// Adds `amount` to the volume of the day of `timestamp`, returns the rolling volume before it
+    pub fn record(&mut self, user: Pubkey, bump: u8, timestamp: i64, amount: u64) -> u64 {
+        if self.user == Pubkey::default() {
+            self.user = user;
+            self.bump = bump;
+        }
+        let volume = self.rolling_volume(timestamp);
+
+        // Clear the buckets of the days that left the window since the last transfer
+        let day = timestamp.div_euclid(Self::SECONDS_PER_DAY);
+        if day - self.last_day >= Self::WINDOW_DAYS as i64 {
+            self.daily_volume = [0; Self::WINDOW_DAYS];
+        } else {
+            for d in self.last_day + 1..=day {
+                self.daily_volume[Self::bucket(d)] = 0;
+            }
+        }
+        self.last_day = self.last_day.max(day);
+
+        let bucket = &mut self.daily_volume[Self::bucket(self.last_day)];
+        *bucket = bucket.saturating_add(amount);
+
+        volume
+    }
+
+    fn bucket(day: i64) -> usize {
+        day.rem_euclid(Self::WINDOW_DAYS as i64) as usize
+    }
+}
+
+impl TransferRecord {
+    pub const USER_OFFSET: usize = 8;
+    pub const STATUS_OFFSET: usize = 48;
+}
+
+impl HookRegistration {
+    // Must match the max_len of accounts
+    pub const MAX_ACCOUNTS: usize = 16;
+
+    /// Checks that the remaining accounts match the registered account layout
+    pub fn validate_accounts(&self, accounts: &[AccountInfo]) -> bool {
+        accounts.len() == self.accounts.len()
+            && accounts
+                .iter()
+                .zip(self.accounts.iter())
+                .all(|(account, meta)| {
+                    (meta.pubkey == Pubkey::default() || account.key() == meta.pubkey)
+                        && (!meta.is_writable || account.is_writable)
+                })
+    }
+}
+
+impl PendingHook {
+    /// Returns the account size needed to store the hook payload of the given message
+    pub fn space_for_message(message: &[u8]) -> Result<usize> {
+        let headers_len = Message::serialized_len(0)? + BurnMessage::serialized_len(0)?;
+        let hook_payload_len = message.len().saturating_sub(headers_len);
+
+        // PendingHook::INIT_SPACE assumes max len of hook_payload is 1 (as specified with #[max_len(1)]).
+        // We subtract that 1 byte and add the full length of the hook payload instead.
+        Ok(Self::DISCRIMINATOR.len() + Self::INIT_SPACE - 1 + hook_payload_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = UserStats::SECONDS_PER_DAY;
+    // Compressed Ed25519 base point, an on-curve key like wallets and keypair token accounts
+    const ED25519_BASE_POINT: [u8; 32] = [
+        0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+        0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+        0x66, 0x66,
+    ];
+
+    fn bridge_config(fee_tiers: Vec<FeeTier>) -> BridgeConfig {
+        BridgeConfig {
+            owner: Pubkey::new_unique(),
+            pending_owner: Pubkey::default(),
+            fee_manager: Pubkey::new_unique(),
+            pauser: Pubkey::new_unique(),
+            domain_manager: Pubkey::new_unique(),
+            compliance: Pubkey::new_unique(),
+            paused: false,
+            usdc_mint: Pubkey::new_unique(),
+            fee_bps: 30,
+            min_fee_amount: 100,
+            max_fee_amount: 1_000_000,
+            fee_tiers,
+            vault_usdc: Pubkey::new_unique(),
+            quote_signer: Pubkey::default(),
+            inbound_reserved: 0,
+            bump: 0,
+            authority_bump: 0,
+            staging_bump: 0,
+            inbound_bump: 0,
+            relayer_authority_bump: 0,
+        }
+    }
+
+    fn tiers() -> Vec<FeeTier> {
+        vec![
+            FeeTier {
+                min_volume: 10_000,
+                fee_bps: 20,
+            },
+            FeeTier {
+                min_volume: 100_000,
+                fee_bps: 10,
+            },
+        ]
+    }
+
+    fn user_stats() -> UserStats {
+        UserStats {
+            user: Pubkey::default(),
+            daily_volume: [0; UserStats::WINDOW_DAYS],
+            last_day: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn fee_tier_is_selected_by_volume() {
+        let config = bridge_config(tiers());
+
+        assert_eq!(config.get_fee_tier(0), 0);
+        assert_eq!(config.get_fee_tier(9_999), 0);
+        assert_eq!(config.get_fee_tier(10_000), 1);
+        assert_eq!(config.get_fee_tier(99_999), 1);
+        assert_eq!(config.get_fee_tier(100_000), 2);
+        assert_eq!(config.get_fee_tier(u64::MAX), 2);
+        assert_eq!(bridge_config(Vec::new()).get_fee_tier(u64::MAX), 0);
+    }
+
+    #[test]
+    fn tiered_fee_uses_tier_rate_within_bounds() {
+        let config = bridge_config(tiers());
+
+        assert_eq!(config.get_tiered_fee_amount(10_000_000, 0).unwrap(), 30_000);
+        assert_eq!(config.get_tiered_fee_amount(10_000_000, 1).unwrap(), 20_000);
+        assert_eq!(config.get_tiered_fee_amount(10_000_000, 2).unwrap(), 10_000);
+        assert_eq!(config.get_fee_amount(10_000_000).unwrap(), 30_000);
+
+        // Clamped to min_fee_amount and max_fee_amount
+        assert_eq!(config.get_tiered_fee_amount(1_000, 2).unwrap(), 100);
+        assert_eq!(
+            config.get_tiered_fee_amount(10_000_000_000, 0).unwrap(),
+            1_000_000
+        );
+
+        // Unknown tier, and amounts not above the fee
+        assert!(config.get_tiered_fee_amount(10_000_000, 3).is_err());
+        assert!(config.get_tiered_fee_amount(100, 0).is_err());
+    }
+
+    #[test]
+    fn fee_tiers_must_be_ordered() {
+        assert!(bridge_config(tiers()).validate());
+
+        let mut unordered = tiers();
+        unordered.reverse();
+        assert!(!bridge_config(unordered).validate());
+
+        let mut over_denominator = tiers();
+        over_denominator[0].fee_bps = BridgeConfig::BPS_DENOMINATOR as u16 + 1;
+        assert!(!bridge_config(over_denominator).validate());
+    }
+
+    #[test]
+    fn rolling_volume_sums_the_window() {
+        let mut stats = user_stats();
+        let user = Pubkey::new_unique();
+        let start = 1_000 * DAY;
+
+        assert_eq!(stats.record(user, 7, start, 100), 0);
+        assert_eq!(stats.user, user);
+        assert_eq!(stats.bump, 7);
+        assert_eq!(stats.record(user, 7, start + 1, 50), 100);
+        assert_eq!(stats.record(user, 7, start + DAY, 25), 150);
+        assert_eq!(stats.rolling_volume(start + DAY), 175);
+
+        // The first day leaves the window after WINDOW_DAYS days
+        assert_eq!(stats.rolling_volume(start + 29 * DAY), 175);
+        assert_eq!(stats.rolling_volume(start + 30 * DAY), 25);
+        assert_eq!(stats.rolling_volume(start + 31 * DAY), 0);
+    }
+
+    #[test]
+    fn rolling_volume_clears_expired_buckets() {
+        let mut stats = user_stats();
+        let user = Pubkey::new_unique();
+        let start = 1_000 * DAY;
+
+        stats.record(user, 0, start, 100);
+        stats.record(user, 0, start + DAY, 10);
+
+        // Same bucket as the first day, its old volume must not be added back
+        assert_eq!(stats.record(user, 0, start + 30 * DAY, 1), 10);
+        assert_eq!(stats.rolling_volume(start + 30 * DAY), 11);
+
+        // A gap longer than the window clears every bucket
+        assert_eq!(stats.record(user, 0, start + 100 * DAY, 5), 0);
+        assert_eq!(stats.daily_volume.iter().sum::<u64>(), 5);
+    }
+
+    #[test]
+    fn rolling_volume_clamps_older_timestamps() {
+        let mut stats = user_stats();
+        let user = Pubkey::new_unique();
+        let start = 1_000 * DAY;
+
+        stats.record(user, 0, start, 100);
+
+        // A timestamp behind last_day is read and booked on last_day
+        assert_eq!(stats.rolling_volume(start - 40 * DAY), 100);
+        assert_eq!(stats.record(user, 0, start - DAY, 10), 100);
+        assert_eq!(stats.last_day, start / DAY);
+        assert_eq!(stats.rolling_volume(start), 110);
+    }
+
+    #[test]
+    fn evm_recipient_must_be_padded() {
+        let mut address = [0u8; 32];
+        address[DomainType::EVM_ADDRESS_PADDING..].fill(0xab);
+        let evm_address = Pubkey::new_from_array(address);
+        address[0] = 1;
+        let unpadded_address = Pubkey::new_from_array(address);
+
+        assert!(DomainType::Evm.is_valid_mint_recipient(&evm_address));
+        assert!(!DomainType::Evm.is_valid_mint_recipient(&unpadded_address));
+        assert!(!DomainType::Evm.is_valid_mint_recipient(&Pubkey::default()));
+
+        assert!(DomainType::Evm.is_valid_destination_caller(&Pubkey::default()));
+        assert!(DomainType::Evm.is_valid_destination_caller(&evm_address));
+        assert!(!DomainType::Evm.is_valid_destination_caller(&unpadded_address));
+    }
+
+    #[test]
+    fn solana_recipient_must_be_set() {
+        let token_account = Pubkey::new_from_array(ED25519_BASE_POINT);
+        let (associated_token_account, _) =
+            Pubkey::find_program_address(&[b"token_account"], &Pubkey::new_unique());
+
+        assert!(DomainType::Solana.is_valid_mint_recipient(&token_account));
+        assert!(DomainType::Solana.is_valid_mint_recipient(&associated_token_account));
+        assert!(!DomainType::Solana.is_valid_mint_recipient(&Pubkey::default()));
+        assert!(DomainType::Solana.is_valid_destination_caller(&token_account));
+    }
+
+    #[test]
+    fn other_recipient_must_be_set() {
+        assert!(DomainType::Other.is_valid_mint_recipient(&Pubkey::new_unique()));
+        assert!(!DomainType::Other.is_valid_mint_recipient(&Pubkey::default()));
+    }
+
+    #[test]
+    fn partner_shares_must_fit_the_fee() {
+        let share = |share_bps| PartnerShare {
+            token_account: Pubkey::new_unique(),
+            share_bps,
+        };
+        let config = |shares| PartnerConfig {
+            partner_id: 1,
+            enabled: true,
+            shares,
+            bump: 0,
+        };
+
+        assert!(config(vec![share(5_000), share(5_000)]).validate());
+        assert!(!config(Vec::new()).validate());
+        assert!(!config(vec![share(5_000), share(5_001)]).validate());
+        assert!(!config(vec![share(0)]).validate());
+        assert!(!config(vec![share(1); PartnerConfig::MAX_SHARES + 1]).validate());
+        assert!(!config(vec![PartnerShare {
+            token_account: Pubkey::default(),
+            share_bps: 100,
+        }])
+        .validate());
+
+        let partner = config(vec![share(3_333)]);
+        assert_eq!(partner.get_share_amount(100, &partner.shares[0]), 33);
+    }
+
+    #[test]
+    fn sponsor_surcharge_rounds_up() {
+        let config = SponsorConfig {
+            enabled: true,
+            fee_payer: Pubkey::new_unique(),
+            usdc_per_sol: 150_000_000,
+            tx_fee_lamports: 5_000,
+            bump: 0,
+        };
+
+        assert_eq!(config.get_surcharge_amount(0).unwrap(), 750);
+        assert_eq!(
+            config
+                .get_surcharge_amount(SponsorConfig::LAMPORTS_PER_SOL - 5_000)
+                .unwrap(),
+            150_000_000
+        );
+        // 2_044_281 lamports with the tx fee are 306_642.15 USDC base units
+        assert_eq!(config.get_surcharge_amount(2_039_281).unwrap(), 306_643);
+        assert!(config.get_surcharge_amount(u64::MAX).is_err());
+        assert!(config.validate());
+        assert!(!SponsorConfig {
+            usdc_per_sol: 0,
+            ..config
+        }
+        .validate());
+    }
+
+    #[test]
+    fn event_rent_subsidy_falls_back_to_the_payer() {
+        let mut counter = UserTransferCounter {
+            user: Pubkey::default(),
+            next_index: 0,
+            subsidized_pending: 0,
+            bump: 0,
+        };
+
+        // A pool short of the required lamports is not used
+        assert!(!counter.start_subsidy(999, 1_000));
+        assert_eq!(counter.subsidized_pending, 0);
+
+        for _ in 0..UserTransferCounter::MAX_SUBSIDIZED_PENDING {
+            assert!(counter.start_subsidy(1_000, 1_000));
+        }
+        // Past the cap the payer pays, until a pool-paid account is reclaimed
+        assert!(!counter.start_subsidy(1_000, 1_000));
+        assert_eq!(
+            counter.subsidized_pending,
+            UserTransferCounter::MAX_SUBSIDIZED_PENDING
+        );
+        counter.end_subsidy();
+        assert!(counter.start_subsidy(1_000, 1_000));
+    }
+}
@@ -0,0 +1,53 @@
+//! SwapAdapter
+
+use {
+    anchor_lang::prelude::*,
+    anchor_lang::solana_program::instruction::{AccountMeta, Instruction},
+};
+
+/// Instruction every swap program plugged into swap_and_deposit_for_burn_cctp must implement.
+/// It is called with the adapter params as its only argument and the caller supplied swap
+/// accounts, in order, and must deliver at least `min_amount_out` USDC to the user USDC account.
+pub const SWAP_INSTRUCTION_NAME: &str = "global:swap";
+
+/// Instruction data passed to the swap program after the instruction discriminator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwapAdapterParams {
+    pub input_mint: Pubkey,
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+}
+
+impl SwapAdapterParams {
+    /// Builds the swap instruction for `swap_program` over `accounts`, keeping their
+    /// signer and writable flags so the user signature is passed through
+    pub fn instruction(
+        &self,
+        swap_program: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> Result<Instruction> {
+        let accounts = accounts
+            .iter()
+            .map(|acc| {
+                if acc.is_writable {
+                    AccountMeta::new(acc.key(), acc.is_signer)
+                } else {
+                    AccountMeta::new_readonly(acc.key(), acc.is_signer)
+                }
+            })
+            .collect();
+
+        let mut data = Vec::with_capacity(8 + 48);
+        data.extend_from_slice(
+            &anchor_lang::solana_program::hash::hash(SWAP_INSTRUCTION_NAME.as_bytes()).to_bytes()
+                [..8],
+        );
+        data.extend_from_slice(&self.try_to_vec()?);
+
+        Ok(Instruction {
+            program_id: *swap_program,
+            accounts,
+            data,
+        })
+    }
+}
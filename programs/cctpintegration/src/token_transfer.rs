@@ -0,0 +1,75 @@
+//! Token-2022 aware transfers of bridge and the fee paths, the CCTP burn itself is SPL Token only
+
+use {
+    crate::error::ErrorCode,
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        token_2022::spl_token_2022::{
+            extension::{
+                transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions,
+            },
+            onchain::invoke_transfer_checked,
+            state::Mint as MintState,
+        },
+        token_interface::{TokenAccount, TransferChecked},
+    },
+};
+
+/// Returns the Token-2022 transfer fee withheld when moving `amount` of `mint`,
+/// zero for SPL Token mints and Token-2022 mints without the transfer fee extension
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+    let Ok(config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+
+    config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or_else(|| error!(ErrorCode::MathOverflow))
+}
+
+/// Returns `amount` minus the transfer fee of `mint`, the amount a destination is credited
+pub fn net_amount(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    Ok(amount - transfer_fee(mint, amount)?)
+}
+
+/// transfer_checked that also resolves transfer hooks: the context remaining accounts carry
+/// the hook program and its extra accounts when the mint has the transfer hook extension
+pub fn transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    invoke_transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+        &ctx.remaining_accounts,
+        amount,
+        decimals,
+        ctx.signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+/// Moves `amount` with transfer_checked and returns the amount `to` was actually credited
+pub fn transfer_checked_net<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    to: &mut InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+    decimals: u8,
+) -> Result<u64> {
+    to.reload()?;
+    let balance_before = to.amount;
+
+    transfer_checked(ctx, amount, decimals)?;
+
+    to.reload()?;
+    to.amount
+        .checked_sub(balance_before)
+        .ok_or_else(|| error!(ErrorCode::InsufficientReceived))
+}
@@ -0,0 +1,220 @@
+//! Constant-product swap program entrypoint
+//!
+//! Minimal x * y = k pool used as the swap program of the cctpintegration
+//! swap-then-bridge adapter in tests. A pool swaps input_mint into output_mint,
+//! its reserves are the balances of the two pool vaults, funded by plain transfers.
+
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+declare_id!("7ddPYpFBCJBXv7uMaCkSAxPHJSZ21e6QNoT3y2yDidon");
+
+#[program]
+pub mod constant_product_swap {
+    use super::*;
+
+    /// Create the pool and its two vaults for the input_mint -> output_mint direction
+    pub fn initialize_pool(ctx: Context<InitializePoolContext>) -> Result<()> {
+        ctx.accounts.pool.set_inner(Pool {
+            input_mint: ctx.accounts.input_mint.key(),
+            output_mint: ctx.accounts.output_mint.key(),
+            input_vault: ctx.accounts.input_vault.key(),
+            output_vault: ctx.accounts.output_vault.key(),
+            bump: ctx.bumps.pool,
+        });
+
+        Ok(())
+    }
+
+    /// Swap params.amount_in of the input mint for at least params.min_amount_out of the output mint.
+    /// Instruction name and parameters follow the cctpintegration swap adapter interface.
+    pub fn swap(ctx: Context<SwapContext>, params: SwapParams) -> Result<()> {
+        require_keys_eq!(
+            params.input_mint,
+            ctx.accounts.pool.input_mint,
+            SwapError::InvalidMint
+        );
+        require_gt!(params.amount_in, 0, SwapError::InvalidAmount);
+
+        let amount_out = Pool::get_amount_out(
+            params.amount_in,
+            ctx.accounts.input_vault.amount,
+            ctx.accounts.output_vault.amount,
+        )?;
+        require_gte!(
+            amount_out,
+            params.min_amount_out,
+            SwapError::SlippageExceeded
+        );
+
+        transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_input.to_account_info(),
+                    mint: ctx.accounts.input_mint.to_account_info(),
+                    to: ctx.accounts.input_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            params.amount_in,
+            ctx.accounts.input_mint.decimals,
+        )?;
+
+        let input_mint = ctx.accounts.pool.input_mint;
+        let output_mint = ctx.accounts.pool.output_mint;
+        let pool_seeds: &[&[&[u8]]] = &[&[
+            b"pool",
+            input_mint.as_ref(),
+            output_mint.as_ref(),
+            &[ctx.accounts.pool.bump],
+        ]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.output_vault.to_account_info(),
+                    mint: ctx.accounts.output_mint.to_account_info(),
+                    to: ctx.accounts.user_output.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                pool_seeds,
+            ),
+            amount_out,
+            ctx.accounts.output_mint.decimals,
+        )?;
+
+        msg!("Swapped {} for {}", params.amount_in, amount_out);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct Pool {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub input_vault: Pubkey,
+    pub output_vault: Pubkey,
+    pub bump: u8,
+}
+
+impl Pool {
+    /// Output of a swap keeping reserve_in * reserve_out constant, rounded down
+    pub fn get_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+        let amount_out = (amount_in as u128 * reserve_out as u128)
+            .checked_div(reserve_in as u128 + amount_in as u128)
+            .ok_or_else(|| error!(SwapError::InvalidAmount))?;
+
+        Ok(amount_out as u64)
+    }
+}
+
+#[derive(Accounts)]
+pub struct InitializePoolContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::DISCRIMINATOR.len() + Pool::INIT_SPACE,
+        seeds = [b"pool", input_mint.key().as_ref(), output_mint.key().as_ref()],
+        bump
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = input_mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"vault", pool.key().as_ref(), input_mint.key().as_ref()],
+        bump
+    )]
+    pub input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        token::mint = output_mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"vault", pool.key().as_ref(), output_mint.key().as_ref()],
+        bump
+    )]
+    pub output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SwapContext<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"pool", pool.input_mint.as_ref(), pool.output_mint.as_ref()],
+        bump = pool.bump,
+        has_one = input_mint @ SwapError::InvalidMint,
+        has_one = output_mint @ SwapError::InvalidMint,
+        has_one = input_vault @ SwapError::InvalidVault,
+        has_one = output_vault @ SwapError::InvalidVault
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        mut,
+        token::mint = input_mint,
+        token::authority = user
+    )]
+    pub user_input: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = output_mint
+    )]
+    pub user_output: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone)]
+pub struct SwapParams {
+    pub input_mint: Pubkey,
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+}
+
+#[error_code]
+pub enum SwapError {
+    #[msg("Invalid mint")]
+    InvalidMint,
+    #[msg("Invalid vault")]
+    InvalidVault,
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Output below the minimum amount")]
+    SlippageExceeded,
+}
@@ -0,0 +1,108 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { ConstantProductSwap } from "../target/types/constant_product_swap";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
+import { assert } from "chai";
+
+describe("constant_product_swap", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.ConstantProductSwap as Program<ConstantProductSwap>;
+  const connection = anchor.getProvider().connection;
+
+  it("Swaps the input mint into USDC along x * y = k", async () => {
+    const user = Keypair.generate();
+
+    // Airdrop SOL to user
+    const signature = await connection.requestAirdrop(user.publicKey, 1000000000);
+    await connection.confirmTransaction(signature);
+
+    // Input token and USDC mint (mock)
+    const inputMint = await createMint(connection, user, user.publicKey, null, 9);
+    const usdcMint = await createMint(connection, user, user.publicKey, null, 6);
+
+    const [pool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pool"), inputMint.toBuffer(), usdcMint.toBuffer()],
+      program.programId
+    );
+    const [inputVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), pool.toBuffer(), inputMint.toBuffer()],
+      program.programId
+    );
+    const [outputVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), pool.toBuffer(), usdcMint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializePool()
+      .accounts({
+        payer: user.publicKey,
+        inputMint: inputMint,
+        outputMint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    // Reserves are the vault balances: 1000 input tokens against 100000 USDC
+    await mintTo(connection, user, inputMint, inputVault, user, 1000_000000000);
+    await mintTo(connection, user, usdcMint, outputVault, user, 100000_000000);
+
+    const userInput = await createAccount(connection, user, inputMint, user.publicKey);
+    const userUsdc = await createAccount(connection, user, usdcMint, user.publicKey);
+    await mintTo(connection, user, inputMint, userInput, user, 10_000000000);
+
+    // 10 * 100000 / (1000 + 10) = 990.099009 USDC
+    await program.methods
+      .swap({
+        inputMint: inputMint,
+        amountIn: new anchor.BN(10_000000000),
+        minAmountOut: new anchor.BN(990_000000),
+      })
+      .accountsPartial({
+        user: user.publicKey,
+        pool: pool,
+        userInput: userInput,
+        userOutput: userUsdc,
+        inputVault: inputVault,
+        outputVault: outputVault,
+        inputMint: inputMint,
+        outputMint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+    const received = await getAccount(connection, userUsdc);
+    assert.equal(received.amount.toString(), "990099009");
+
+    // A minimum above the curve output is refused
+    try {
+      await program.methods
+        .swap({
+          inputMint: inputMint,
+          amountIn: new anchor.BN(1_000000000),
+          minAmountOut: new anchor.BN(1000_000000),
+        })
+        .accountsPartial({
+          user: user.publicKey,
+          pool: pool,
+          userInput: userInput,
+          userOutput: userUsdc,
+          inputVault: inputVault,
+          outputVault: outputVault,
+          inputMint: inputMint,
+          outputMint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("swap below the minimum output succeeded");
+    } catch (error) {
+      assert.include(error.toString(), "SlippageExceeded");
+    }
+  });
+});